@@ -24,6 +24,17 @@ mod polynomial_benches {
             }
         }
 
+        {
+            let mut vss_sk_gen_batched: BenchmarkGroup<_> =
+                c.benchmark_group("VSS secret key generation with batched randomness");
+            for n in SIZES {
+                let t = n / 3;
+                vss_sk_gen_batched.bench_function(format!("n={}, t={}", n, t).as_str(), |b| {
+                    b.iter(|| Poly::<bls12381::Scalar>::rand_batched(t as u32, &mut thread_rng()))
+                });
+            }
+        }
+
         {
             let mut vss_pk_gen: BenchmarkGroup<_> = c.benchmark_group("VSS public key generation");
             for n in SIZES {
@@ -50,6 +61,45 @@ mod polynomial_benches {
             }
         }
 
+        {
+            // Horner's method (the default) vs Estrin's scheme to decide whether the latter should
+            // be used above some degree.
+            let mut eval: BenchmarkGroup<_> = c.benchmark_group("Eval Horner vs Estrin");
+            for n in SIZES {
+                let t = n / 3;
+                let vss_pk =
+                    Poly::<bls12381::Scalar>::rand(t as u32, &mut thread_rng()).commit::<G>();
+                let i = NonZeroU32::new(n as u32).unwrap();
+                eval.bench_function(format!("Horner, t={}", t).as_str(), |b| {
+                    b.iter(|| vss_pk.eval(i))
+                });
+                eval.bench_function(format!("Estrin, t={}", t).as_str(), |b| {
+                    b.iter(|| vss_pk.eval_estrin(i))
+                });
+            }
+        }
+
+        {
+            let mut eval_committee: BenchmarkGroup<_> =
+                c.benchmark_group("Evaluate public polynomial for the committee");
+            for n in SIZES {
+                let t = n / 3;
+                let vss_pk =
+                    Poly::<bls12381::Scalar>::rand(t as u32, &mut thread_rng()).commit::<G>();
+                eval_committee.bench_function(format!("Horner, n={}, t={}", n, t).as_str(), |b| {
+                    b.iter(|| {
+                        (1u32..=(n as u32))
+                            .map(|i| vss_pk.eval(NonZeroU32::new(i).unwrap()))
+                            .collect::<Vec<_>>()
+                    })
+                });
+                eval_committee.bench_function(
+                    format!("Forward differences, n={}, t={}", n, t).as_str(),
+                    |b| b.iter(|| vss_pk.eval_committee_msm(n as u32).unwrap()),
+                );
+            }
+        }
+
         {
             // k represents the maximal number of shares per one party.
             // Note that in DKG protocol, each party verifies its shares vs O(f+1) VSS public keys,
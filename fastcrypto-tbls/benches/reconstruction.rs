@@ -0,0 +1,172 @@
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use fastcrypto::groups::bls12381::{G1Element, G2Element};
+use fastcrypto::groups::ristretto255::RistrettoPoint;
+use fastcrypto::groups::{GroupElement, MultiScalarMul};
+use fastcrypto_tbls::polynomial::{Eval, Poly, SubsetSelection};
+use rand::thread_rng;
+use std::num::NonZeroU32;
+
+mod reconstruction_benches {
+    use super::*;
+
+    const THRESHOLDS: [u32; 5] = [4, 16, 64, 256, 1024];
+
+    fn reconstruction_for_group<G: GroupElement + MultiScalarMul>(name: &str, c: &mut Criterion) {
+        // For each threshold t, a polynomial of degree t-1 and t shares in the exp.
+        let cases = THRESHOLDS
+            .iter()
+            .map(|t| {
+                let vss_sk = Poly::<G::ScalarType>::rand(*t - 1, &mut thread_rng());
+                let vss_pk = vss_sk.commit::<G>();
+                let shares = (1..=*t)
+                    .map(|i| vss_pk.eval(NonZeroU32::new(i).unwrap()))
+                    .collect::<Vec<Eval<G>>>();
+                (*t, vss_sk, vss_pk, shares)
+            })
+            .collect::<Vec<_>>();
+
+        {
+            let mut eval: BenchmarkGroup<_> = c.benchmark_group(format!("Eval/{}", name));
+            for (t, _, vss_pk, _) in &cases {
+                eval.bench_function(format!("t={}", t).as_str(), |b| {
+                    b.iter(|| vss_pk.eval(NonZeroU32::new(*t).unwrap()))
+                });
+            }
+        }
+
+        {
+            let mut commit: BenchmarkGroup<_> = c.benchmark_group(format!("Commit/{}", name));
+            for (t, vss_sk, _, _) in &cases {
+                commit.bench_function(format!("t={}", t).as_str(), |b| {
+                    b.iter(|| vss_sk.commit::<G>())
+                });
+            }
+        }
+
+        {
+            let mut recover: BenchmarkGroup<_> = c.benchmark_group(format!("Recover c0/{}", name));
+            for (t, _, _, shares) in &cases {
+                recover.bench_function(format!("t={}", t).as_str(), |b| {
+                    b.iter(|| Poly::<G>::recover_c0(*t, shares.iter()).unwrap())
+                });
+            }
+        }
+
+        {
+            // Recovering the secret from shares, i.e., scalars, is dominated by the computation of
+            // the Lagrange coefficients, whose denominators are inverted in one batch.
+            let mut recover_secret: BenchmarkGroup<_> =
+                c.benchmark_group(format!("Recover secret/{}", name));
+            for (t, vss_sk, _, _) in &cases {
+                let shares = (1..=*t)
+                    .map(|i| vss_sk.eval(NonZeroU32::new(i).unwrap()))
+                    .collect::<Vec<_>>();
+                recover_secret.bench_function(format!("t={}", t).as_str(), |b| {
+                    b.iter(|| Poly::<G::ScalarType>::recover_c0(*t, shares.iter()).unwrap())
+                });
+            }
+        }
+
+        {
+            // Repeated reconstructions from overlapping sets of shares.
+            let mut recover_repeated: BenchmarkGroup<_> =
+                c.benchmark_group(format!("Recover c0 10 times/{}", name));
+            for (t, vss_sk, _, _) in &cases {
+                let vss_pk = vss_sk.commit::<G>();
+                let shares = (1..=2 * *t)
+                    .map(|i| vss_pk.eval(NonZeroU32::new(i).unwrap()))
+                    .collect::<Vec<Eval<G>>>();
+                let step = (*t as usize / 10).max(1);
+                recover_repeated.bench_function(format!("t={}", t).as_str(), |b| {
+                    b.iter(|| {
+                        (0..10).for_each(|j| {
+                            let subset = shares.iter().skip(j * step).take(*t as usize);
+                            Poly::<G>::recover_c0(*t, subset).unwrap();
+                        })
+                    })
+                });
+                let index_scalars = Poly::<G>::index_scalars(2 * *t);
+                recover_repeated.bench_function(
+                    format!("t={}, with precomputed index scalars", t).as_str(),
+                    |b| {
+                        b.iter(|| {
+                            (0..10).for_each(|j| {
+                                let subset = shares.iter().skip(j * step).take(*t as usize);
+                                Poly::<G>::recover_c0_with_index_scalars(
+                                    *t,
+                                    subset,
+                                    &index_scalars,
+                                )
+                                .unwrap();
+                            })
+                        })
+                    },
+                );
+            }
+        }
+
+        {
+            // Reconstruction from shares with sparse indices: The smallest indices are spread out
+            // over [1, 2^31] such that the products of their differences quickly overflow a u128
+            // (for t >= 6), while SubsetSelection::MinimalSpread selects the consecutive indices
+            // above 2^31, whose Lagrange coefficients mostly stay in the u128 fast path.
+            let mut recover_sparse: BenchmarkGroup<_> =
+                c.benchmark_group(format!("Recover c0 from sparse indices/{}", name));
+            for (t, vss_sk, _, _) in &cases {
+                let vss_pk = vss_sk.commit::<G>();
+                let stride = (1 << 31) / *t;
+                let indices = (1..=*t)
+                    .map(|i| i * stride)
+                    .chain((1..=*t).map(|i| (1 << 31) + i));
+                let shares = indices
+                    .map(|i| vss_pk.eval(NonZeroU32::new(i).unwrap()))
+                    .collect::<Vec<Eval<G>>>();
+                for selection in [
+                    SubsetSelection::SmallestIndices,
+                    SubsetSelection::MinimalSpread,
+                ] {
+                    recover_sparse.bench_function(
+                        format!("t={}, {:?}", t, selection).as_str(),
+                        |b| {
+                            b.iter(|| {
+                                Poly::<G>::recover_c0_with_slack_and_selection(
+                                    *t,
+                                    shares.iter(),
+                                    selection,
+                                )
+                                .unwrap()
+                            })
+                        },
+                    );
+                }
+            }
+        }
+
+        {
+            let mut recover_msm: BenchmarkGroup<_> =
+                c.benchmark_group(format!("Recover c0 with MSM/{}", name));
+            for (t, _, _, shares) in &cases {
+                recover_msm.bench_function(format!("t={}", t).as_str(), |b| {
+                    b.iter(|| Poly::<G>::recover_c0_msm(*t, shares.iter()).unwrap())
+                });
+            }
+        }
+    }
+
+    fn reconstruction(c: &mut Criterion) {
+        reconstruction_for_group::<G1Element>("BLS12381-G1", c);
+        reconstruction_for_group::<G2Element>("BLS12381-G2", c);
+        reconstruction_for_group::<RistrettoPoint>("Ristretto255", c);
+    }
+
+    criterion_group! {
+        name = reconstruction_benches;
+        config = Criterion::default();
+        targets = reconstruction,
+    }
+}
+
+criterion_main!(reconstruction_benches::reconstruction_benches);
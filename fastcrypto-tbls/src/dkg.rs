@@ -254,12 +254,14 @@ where
             })
             .map_err(|_| FastCryptoError::InvalidMessage)?;
 
-        if self.t != msg.vss_pk.degree() + 1 {
+        // An empty vss_pk has no degree and is rejected as well.
+        let degree = msg.vss_pk.degree_checked().ok();
+        if degree != Some(self.t - 1) {
             warn!(
-                "DKG: Message sanity check failed for id {}, expected degree={}, got {}",
+                "DKG: Message sanity check failed for id {}, expected degree={}, got {:?}",
                 msg.sender,
                 self.t - 1,
-                msg.vss_pk.degree()
+                degree
             );
             return Err(FastCryptoError::InvalidMessage);
         }
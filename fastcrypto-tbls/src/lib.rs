@@ -25,6 +25,9 @@ pub mod types;
 #[cfg(any(test, feature = "experimental"))]
 pub mod nidkg;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
 #[cfg(test)]
 #[path = "tests/tbls_tests.rs"]
 pub mod tbls_tests;
@@ -56,3 +59,7 @@ pub mod nidkg_tests;
 #[cfg(test)]
 #[path = "tests/nizk_tests.rs"]
 pub mod nizk_tests;
+
+#[cfg(test)]
+#[path = "tests/test_utils_tests.rs"]
+pub mod test_utils_tests;
@@ -5,44 +5,284 @@
 // modified for our needs.
 //
 
-use crate::types::{IndexedValue, ShareIndex};
+use crate::dl_verification::{batch_coefficients, verify_pairs};
+use crate::nizk::DLNizk;
+use crate::random_oracle::RandomOracle;
+use crate::types::{scalar_from_bytes, scalar_to_bytes, IndexedValue, ShareIndex};
+use digest::Digest;
 use fastcrypto::error::{FastCryptoError, FastCryptoResult};
-use fastcrypto::groups::{GroupElement, MultiScalarMul, Scalar};
+use fastcrypto::groups::{FiatShamirChallenge, GroupElement, MultiScalarMul, Pairing, Scalar};
+use fastcrypto::serde_helpers::ToFromByteArray;
 use fastcrypto::traits::AllowedRng;
-use itertools::Either;
+use itertools::{Either, Itertools};
+use rand::seq::SliceRandom;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha3::Sha3_256;
 use std::borrow::Borrow;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::ops::{Deref, Index, IndexMut};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Types
 
+/// Default domain separation tag of [Poly::transcript_hash].
+pub const TRANSCRIPT_HASH_DST: &[u8] = b"fastcrypto-tbls-poly-transcript";
+
+/// Domain separation tag of [Poly::share_set_fingerprint].
+pub const SHARE_SET_FINGERPRINT_DST: &[u8] = b"fastcrypto-tbls-share-set-fingerprint";
+
+/// Default domain separation tag of [Poly::verify_shares_fiat_shamir].
+pub const FIAT_SHAMIR_DST: &[u8] = b"fastcrypto-tbls-poly-fiat-shamir";
+
+/// A domain separation tag for the Fiat-Shamir challenges and transcript hashes derived from
+/// polynomials. Protocols using these should choose distinct tags so that their challenges and
+/// hashes cannot collide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainSeparationTag(Vec<u8>);
+
+impl DomainSeparationTag {
+    pub fn new(tag: &[u8]) -> Self {
+        Self(tag.to_vec())
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 pub type Eval<A> = IndexedValue<A>;
 
 /// A polynomial that is using a scalar for the variable x and a generic
 /// element for the coefficients.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Poly<C>(Vec<C>);
+pub struct Poly<C>(
+    Vec<C>,
+    #[cfg(feature = "cached")]
+    #[serde(skip)]
+    EvalCache<C>,
+);
+
+/// A polynomial with a version tag for its wire format, so that the format can evolve (e.g., to a
+/// compressed encoding) while polynomials serialized with older versions can still be recognized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VersionedPoly<C> {
+    V1(Poly<C>),
+}
 
 pub type PrivatePoly<C> = Poly<<C as GroupElement>::ScalarType>;
 pub type PublicPoly<C> = Poly<C>;
 
+/// A public polynomial together with shares and their public counterparts (generator * share),
+/// see [Poly::deal_with_public].
+pub type DealingWithPublic<C, P> = (PublicPoly<P>, Vec<(Eval<C>, Eval<P>)>);
+
+/// The evaluations g^{s_i} of a public polynomial for the indices i = 1, ..., n, computed once
+/// so that, e.g., verifying partial signatures of a fixed committee does not require evaluating
+/// the polynomial each time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKeyShares<C>(Vec<C>);
+
+/// Accumulates shares received one at a time or in batches, e.g., from a stream, until the
+/// constant term can be recovered with [C0Recovery::finalize]. Since the Lagrange coefficients
+/// depend on the whole set of indices, the shares are kept until then.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct C0Recovery<C> {
+    t: u32,
+    shares: BTreeMap<ShareIndex, C>,
+    /// Set if a share was added with the same index as an earlier one but a different value.
+    conflicting: bool,
+}
+
+/// A polynomial together with a Merkle tree over its serialized coefficients, whose root is a
+/// compact commitment to the coefficients (instead of committing to each coefficient in a group).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerklePoly<C> {
+    poly: Poly<C>,
+    /// The leaves (padded with [MERKLE_PADDING] to a power of two) are the first layer and the
+    /// root is the only element of the last one.
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+/// The authentication path of a coefficient in a [MerklePoly], i.e., the siblings of the nodes on
+/// the path from its leaf to the root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerklePath(Vec<[u8; 32]>);
+
+/// Leaf of the Merkle tree of a [MerklePoly] that does not correspond to a coefficient. It is not
+/// the hash of any leaf or node, thus cannot be opened.
+const MERKLE_PADDING: [u8; 32] = [0u8; 32];
+const MERKLE_LEAF_PREFIX: u8 = 0;
+const MERKLE_NODE_PREFIX: u8 = 1;
+const MERKLE_ROOT_PREFIX: u8 = 2;
+
+/// A secret, e.g., reconstructed by [Poly::recover_secret], which is zeroized when dropped. The
+/// secret is accessible via [Deref] and is not printed by [Debug].
+pub struct Secret<C: Zeroize>(C);
+
+impl<C: Zeroize> Deref for Secret<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C: Zeroize> Zeroize for Secret<C> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<C: Zeroize> Drop for Secret<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C: Zeroize> ZeroizeOnDrop for Secret<C> {}
+
+impl<C: Zeroize> std::fmt::Debug for Secret<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret(<elided>)")
+    }
+}
+
+/// Strategies for selecting `t` out of more than `t` polynomial evaluations for reconstruction.
+/// Since any `t` valid evaluations determine the same polynomial, all strategies yield the same
+/// secret and only differ in performance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubsetSelection {
+    /// The evaluations with the smallest indices, see [Poly::select_subset].
+    #[default]
+    SmallestIndices,
+    /// The evaluations whose indices are closest to each other, see
+    /// [Poly::select_subset_min_spread].
+    MinimalSpread,
+}
+
+/// Largest index whose evaluation is memoized by [Poly::eval] if the `cached` feature is enabled.
+/// Committee indices are small, so this bounds the cache of each polynomial to
+/// `EVAL_CACHE_MAX_INDEX` entries even if the indices are chosen by an adversary.
+#[cfg(feature = "cached")]
+pub const EVAL_CACHE_MAX_INDEX: u32 = 4096;
+
+/// Evaluations memoized by [Poly::eval] if the `cached` feature is enabled. The cache is not
+/// serialized and is ignored when comparing polynomials. Only evaluations of public polynomials
+/// are memoized, see [Poly::is_cacheable], so secret shares never outlive their polynomial in the
+/// cache, and only for indices up to [EVAL_CACHE_MAX_INDEX].
+#[cfg(feature = "cached")]
+#[derive(Debug)]
+struct EvalCache<C>(std::sync::Mutex<std::collections::HashMap<ShareIndex, C>>);
+
+#[cfg(feature = "cached")]
+impl<C> Default for EvalCache<C> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+#[cfg(feature = "cached")]
+impl<C: Clone> Clone for EvalCache<C> {
+    fn clone(&self) -> Self {
+        Self(std::sync::Mutex::new(
+            self.0.lock().expect("not poisoned").clone(),
+        ))
+    }
+}
+
+#[cfg(feature = "cached")]
+impl<C> PartialEq for EvalCache<C> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "cached")]
+impl<C> Eq for EvalCache<C> {}
+
+#[cfg(feature = "cached")]
+impl<C> EvalCache<C> {
+    fn clear(&mut self) {
+        self.0.get_mut().expect("not poisoned").clear();
+    }
+}
+
+#[cfg(feature = "cached")]
+impl<C: Copy> EvalCache<C> {
+    fn get(&self, i: ShareIndex) -> Option<C> {
+        self.0.lock().expect("not poisoned").get(&i).copied()
+    }
+
+    fn insert(&self, i: ShareIndex, value: C) {
+        self.0.lock().expect("not poisoned").insert(i, value);
+    }
+}
+
 /// Vector related operations.
 
 impl<C> Poly<C> {
-    /// Returns the degree of the polynomial
+    /// Returns the degree of the polynomial. Panics if the polynomial has no coefficients, which
+    /// is only possible for polynomials constructed with [Poly::from] an empty vector or
+    /// deserialized from untrusted input. Use [Poly::degree_checked] for such polynomials.
     pub fn degree(&self) -> u32 {
         // e.g. c_0 + c_1 * x + c_2 * x^2 + c_3 * x^3
         // ^ 4 coefficients correspond to a 3rd degree poly
-        (self.0.len() - 1) as u32
+        self.degree_checked()
+            .expect("polynomial has at least one coefficient")
+    }
+
+    /// Same as [Poly::degree], but returns [FastCryptoError::InvalidInput] instead of panicking if
+    /// the polynomial has no coefficients.
+    pub fn degree_checked(&self) -> FastCryptoResult<u32> {
+        self.0
+            .len()
+            .checked_sub(1)
+            .map(|degree| degree as u32)
+            .ok_or(FastCryptoError::InvalidInput)
+    }
+
+    /// Drops the memoized evaluations (if any) since the coefficients are about to change.
+    fn invalidate_cache(&mut self) {
+        #[cfg(feature = "cached")]
+        self.1.clear();
     }
 }
 
+#[cfg(not(feature = "cached"))]
 impl<C> From<Vec<C>> for Poly<C> {
     fn from(c: Vec<C>) -> Self {
         Self(c)
     }
 }
 
+#[cfg(feature = "cached")]
+impl<C> From<Vec<C>> for Poly<C> {
+    fn from(c: Vec<C>) -> Self {
+        Self(c, EvalCache::default())
+    }
+}
+
+/// Returns the i-th coefficient of the polynomial. Panics if i > degree(), like [Vec] does.
+impl<C> Index<usize> for Poly<C> {
+    type Output = C;
+
+    fn index(&self, i: usize) -> &C {
+        &self.0[i]
+    }
+}
+
+/// Returns a mutable reference to the i-th coefficient of the polynomial. Panics if i > degree(),
+/// like [Vec] does.
+impl<C> IndexMut<usize> for Poly<C> {
+    fn index_mut(&mut self, i: usize) -> &mut C {
+        self.invalidate_cache();
+        &mut self.0[i]
+    }
+}
+
 /// GroupElement operations.
 
 impl<C: GroupElement> Poly<C> {
@@ -53,6 +293,7 @@ impl<C: GroupElement> Poly<C> {
 
     /// Performs polynomial addition in place.
     pub fn add(&mut self, other: &Self) {
+        self.invalidate_cache();
         // if we have a smaller degree we should pad with zeros
         if self.0.len() < other.0.len() {
             self.0.resize(other.0.len(), C::zero())
@@ -60,26 +301,117 @@ impl<C: GroupElement> Poly<C> {
         self.0.iter_mut().zip(&other.0).for_each(|(a, b)| *a += *b)
     }
 
+    /// Same as [Poly::add], but first checks that all coefficients of both polynomials have the
+    /// same group parameters as given by `parameters`, e.g., an identifier of the curve
+    /// configuration for groups that are only known at runtime. For the groups in fastcrypto, the
+    /// type system already guarantees this. Returns [FastCryptoError::InvalidInput] and leaves
+    /// the polynomial unchanged if the parameters differ.
+    pub fn checked_add_same_type<P: PartialEq>(
+        &mut self,
+        other: &Self,
+        parameters: impl Fn(&C) -> P,
+    ) -> FastCryptoResult<()> {
+        let mut all_parameters = self.0.iter().chain(&other.0).map(parameters);
+        // Two polynomials without coefficients trivially have the same parameters.
+        if let Some(first) = all_parameters.next() {
+            if !all_parameters.all(|p| p == first) {
+                return Err(FastCryptoError::InvalidInput);
+            }
+        }
+        self.add(other);
+        Ok(())
+    }
+
     // TODO: Some of the functions/steps below may be executed many times in practice thus cache can be
     // used to improve efficiency (e.g., eval(i) may be called with the same index every time a partial
     // signature from party i is verified).
 
-    /// Evaluates the polynomial at the specified value.
+    /// Evaluates the polynomial at the specified value. If the `cached` feature is enabled, the
+    /// evaluations of public polynomials at indices up to [EVAL_CACHE_MAX_INDEX] are memoized.
     pub fn eval(&self, i: ShareIndex) -> Eval<C> {
-        // Use Horner's Method to evaluate the polynomial.
-        let xi = C::ScalarType::from(i.get().into());
-        let res = self
-            .0
-            .iter()
-            .rev()
-            .fold(C::zero(), |sum, coeff| sum * xi + coeff);
+        #[cfg(feature = "cached")]
+        if let Some(value) = self.1.get(i) {
+            return Eval { index: i, value };
+        }
+        let value = self.eval_at_scalar(&C::ScalarType::from(i.get().into()));
+        #[cfg(feature = "cached")]
+        if i.get() <= EVAL_CACHE_MAX_INDEX && Self::is_cacheable() {
+            self.1.insert(i, value);
+        }
+        Eval { index: i, value }
+    }
+
+    /// Returns false for polynomials over scalars, i.e., private polynomials whose evaluations are
+    /// secret shares. Scalars are the only group elements that are their own scalar type. Since
+    /// [GroupElement] does not require `'static` (which [std::any::TypeId] needs), the types are
+    /// compared by name; distinct types with the same name are conservatively not cached.
+    #[cfg(feature = "cached")]
+    fn is_cacheable() -> bool {
+        std::any::type_name::<C>() != std::any::type_name::<C::ScalarType>()
+    }
+
+    #[cfg(all(test, feature = "cached"))]
+    pub(crate) fn cached_evals(&self) -> usize {
+        self.1 .0.lock().expect("not poisoned").len()
+    }
 
+    /// Same as [Poly::eval], but uses Estrin's scheme instead of Horner's method, i.e., pairs of
+    /// coefficients are combined as c_{2j} + c_{2j+1} * x and the same is repeated with x^2 until
+    /// one value is left. The evaluations within each level are independent, which may be faster
+    /// for polynomials of high degree. Evaluations are not memoized.
+    pub fn eval_estrin(&self, i: ShareIndex) -> Eval<C> {
+        let mut x = C::ScalarType::from(i.get().into());
+        let mut level = self.0.clone();
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [even, odd] => *even + *odd * x,
+                    _ => pair[0],
+                })
+                .collect();
+            x = x * x;
+        }
+        // A polynomial without coefficients evaluates to zero, as with Horner's method.
         Eval {
             index: i,
-            value: res,
+            value: level.first().copied().unwrap_or_else(C::zero),
         }
     }
 
+    /// Evaluates the polynomial at an arbitrary scalar.
+    pub fn eval_at_scalar(&self, x: &C::ScalarType) -> C {
+        // Use Horner's Method to evaluate the polynomial.
+        self.0
+            .iter()
+            .rev()
+            .fold(C::zero(), |sum, coeff| sum * x + coeff)
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's method as [Poly::eval_at_scalar], while
+    /// folding `f` over the steps, e.g., to hash the intermediate states for a proof. Starting from
+    /// the leading coefficient, each step updates the accumulator to `acc * x + coeff` and then
+    /// calls `f(state, &acc, &coeff)`. The accumulator after the last step is the evaluation.
+    pub fn fold_eval<S>(&self, x: &C::ScalarType, init: S, mut f: impl FnMut(S, &C, &C) -> S) -> S {
+        let mut acc = C::zero();
+        self.0.iter().rev().fold(init, |state, coeff| {
+            acc = acc * x + coeff;
+            f(state, &acc, coeff)
+        })
+    }
+
+    /// Evaluates the polynomial at -i, e.g., for interpolation domains that are symmetric around
+    /// zero.
+    pub fn eval_at_neg_index(&self, i: ShareIndex) -> C {
+        self.eval_at_scalar(&-C::ScalarType::from(i.get().into()))
+    }
+
+    /// Returns the sum of the coefficients, which equals the evaluation of the polynomial at x = 1.
+    /// This is a cheap quantity for consistency checks.
+    pub fn sum_of_coefficients(&self) -> C {
+        self.eval_at_scalar(&C::ScalarType::generator())
+    }
+
     // Multiply using u128 if possible, otherwise just convert one element to the group element and return the other.
     pub fn fast_mult(x: u128, y: u128) -> Either<(C::ScalarType, u128), u128> {
         if x.leading_zeros() >= (128 - y.leading_zeros()) {
@@ -90,9 +422,11 @@ impl<C: GroupElement> Poly<C> {
     }
 
     // Expects exactly t unique shares.
+    // If given, index_scalars[i - 1] is used as the scalar of index i (see [Poly::index_scalars]).
     fn get_lagrange_coefficients_for_c0(
         t: u32,
         mut shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+        index_scalars: Option<&[C::ScalarType]>,
     ) -> FastCryptoResult<Vec<C::ScalarType>> {
         let mut ids_set = HashSet::new();
         let (shares_size_lower, shares_size_upper) = shares.size_hint();
@@ -110,16 +444,32 @@ impl<C: GroupElement> Poly<C> {
         if indices.len() != t as usize {
             return Err(FastCryptoError::InvalidInput);
         }
+        Ok(Self::get_lagrange_coefficients_for_c0_from_indices(
+            &indices,
+            index_scalars,
+        ))
+    }
 
-        let full_numerator = indices.iter().fold(C::ScalarType::generator(), |acc, i| {
-            acc * C::ScalarType::from(*i)
-        });
+    /// Same as above for indices that are already known to be unique.
+    fn get_lagrange_coefficients_for_c0_from_indices(
+        indices: &[u128],
+        index_scalars: Option<&[C::ScalarType]>,
+    ) -> Vec<C::ScalarType> {
+        let to_scalar = |i: u128| {
+            index_scalars
+                .and_then(|scalars| scalars.get(i as usize - 1))
+                .copied()
+                .unwrap_or_else(|| C::ScalarType::from(i))
+        };
+        let full_numerator = indices
+            .iter()
+            .fold(C::ScalarType::generator(), |acc, i| acc * to_scalar(*i));
 
-        let mut coeffs = Vec::new();
-        for i in &indices {
+        let mut denominators = Vec::with_capacity(indices.len());
+        for i in indices {
             let mut negative = false;
             let (mut denominator, remaining) = indices.iter().filter(|j| *j != i).fold(
-                (C::ScalarType::from(*i), 1u128),
+                (to_scalar(*i), 1u128),
                 |(prev_acc, remaining), j| {
                     let diff = if i > j {
                         negative = !negative;
@@ -140,10 +490,36 @@ impl<C: GroupElement> Poly<C> {
             if negative {
                 denominator = -denominator;
             }
-            let coeff = full_numerator / denominator;
-            coeffs.push(coeff.expect("safe since i != j"));
+            denominators.push(denominator);
+        }
+        batch_inverse(&denominators)
+            .expect("safe since i != j")
+            .into_iter()
+            .map(|inverse| full_numerator * inverse)
+            .collect()
+    }
+
+    /// Returns the sum of the Lagrange coefficients used by [Poly::recover_c0] for the given
+    /// indices. Since interpolating the constant polynomial 1 gives \sum_j \lambda_j * 1 = 1, the
+    /// sum must be one for any (non-empty) set of unique indices, which makes this a cheap sanity
+    /// check. Returns an error if the indices are empty or not unique.
+    pub fn lagrange_coefficients_sum(indices: &[ShareIndex]) -> FastCryptoResult<C::ScalarType> {
+        if indices.is_empty() || indices.iter().collect::<HashSet<_>>().len() != indices.len() {
+            return Err(FastCryptoError::InvalidInput);
         }
-        Ok(coeffs)
+        let indices = indices.iter().map(|i| i.get() as u128).collect::<Vec<_>>();
+        Ok(
+            Self::get_lagrange_coefficients_for_c0_from_indices(&indices, None)
+                .into_iter()
+                .fold(C::ScalarType::zero(), |acc, c| acc + c),
+        )
+    }
+
+    /// Returns true if the two sets of indices are equal, ignoring order and duplicates. Since the
+    /// Lagrange coefficients only depend on the set of indices, reconstructions from equal sets can
+    /// reuse the same coefficients (reordered accordingly).
+    pub fn same_index_set(a: &[ShareIndex], b: &[ShareIndex]) -> bool {
+        a.iter().collect::<HashSet<_>>() == b.iter().collect::<HashSet<_>>()
     }
 
     /// Given exactly `t` polynomial evaluations, it will recover the polynomial's constant term.
@@ -151,7 +527,77 @@ impl<C: GroupElement> Poly<C> {
         t: u32,
         shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
     ) -> Result<C, FastCryptoError> {
-        let coeffs = Self::get_lagrange_coefficients_for_c0(t, shares.clone())?;
+        let coeffs = Self::get_lagrange_coefficients_for_c0(t, shares.clone(), None)?;
+        let plain_shares = shares.map(|s| s.borrow().value);
+        let res = coeffs
+            .iter()
+            .zip(plain_shares)
+            .fold(C::zero(), |acc, (c, s)| acc + (s * *c));
+        Ok(res)
+    }
+
+    /// Same as [Poly::recover_c0] for evaluations from a fallible source, e.g., deserialized on the
+    /// fly. Stops at and returns the first error.
+    pub fn recover_c0_try(
+        t: u32,
+        shares: impl Iterator<Item = FastCryptoResult<Eval<C>>>,
+    ) -> FastCryptoResult<C> {
+        let shares = shares.collect::<FastCryptoResult<Vec<_>>>()?;
+        Self::recover_c0(t, shares.iter())
+    }
+
+    /// Same as [Poly::recover_c0], but evaluations that are repeated with the same index and the
+    /// same value are ignored, e.g., when collecting shares from a gossip network. Returns
+    /// [FastCryptoError::InvalidInput] if the same index appears with conflicting values or if
+    /// there are not exactly `t` unique evaluations.
+    pub fn recover_c0_dedup(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+    ) -> FastCryptoResult<C> {
+        let mut unique = BTreeMap::new();
+        for s in shares {
+            let s = s.borrow();
+            if *unique.entry(s.index).or_insert(s.value) != s.value {
+                return Err(FastCryptoError::InvalidInput); // conflicting values
+            }
+        }
+        if unique.len() != t as usize {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Self::recover_c0_from_map(t, &unique)
+    }
+
+    /// Same as [Poly::recover_c0], but first checks that the given public polynomial has degree
+    /// `t-1`, i.e., that the threshold used for the reconstruction matches the one of the
+    /// commitment. Returns [FastCryptoError::InvalidInput] otherwise.
+    pub fn recover_c0_checked_against(
+        public: &PublicPoly<C>,
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
+    ) -> FastCryptoResult<C> {
+        if t == 0 || public.degree_checked()? != t - 1 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Self::recover_c0(t, shares)
+    }
+
+    /// Returns the scalars of the indices 1, ..., n, to be reused in multiple calls to
+    /// [Poly::recover_c0_with_index_scalars].
+    pub fn index_scalars(n: u32) -> Vec<C::ScalarType> {
+        (1..=n).map(|i| C::ScalarType::from(i.into())).collect()
+    }
+
+    /// Same as [Poly::recover_c0], but uses precomputed scalars of the indices as returned by
+    /// [Poly::index_scalars] instead of converting the indices of the evaluations each time, e.g.,
+    /// for repeated reconstructions over overlapping sets of indices. Indices that are not covered
+    /// by `index_scalars` are converted as usual.
+    pub fn recover_c0_with_index_scalars(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
+        index_scalars: &[C::ScalarType],
+    ) -> FastCryptoResult<C> {
+        let coeffs =
+            Self::get_lagrange_coefficients_for_c0(t, shares.clone(), Some(index_scalars))?;
         let plain_shares = shares.map(|s| s.borrow().value);
         let res = coeffs
             .iter()
@@ -160,62 +606,1836 @@ impl<C: GroupElement> Poly<C> {
         Ok(res)
     }
 
+    /// Same as [Poly::recover_c0], but calls `progress(k)` after the k-th evaluation has been
+    /// incorporated, e.g., to report progress of a slow reconstruction.
+    pub fn recover_c0_with_progress(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
+        mut progress: impl FnMut(usize),
+    ) -> FastCryptoResult<C> {
+        let coeffs = Self::get_lagrange_coefficients_for_c0(t, shares.clone(), None)?;
+        let plain_shares = shares.map(|s| s.borrow().value);
+        let mut res = C::zero();
+        for (k, (c, s)) in coeffs.iter().zip(plain_shares).enumerate() {
+            res += s * *c;
+            progress(k + 1);
+        }
+        Ok(res)
+    }
+
+    /// Given exactly `t` polynomial evaluations, it will return the evaluations of the
+    /// polynomial over the given domain (i.e., its evaluation form, e.g., for an NTT-based
+    /// pipeline). The polynomial is interpolated once, in O(t^2), and then evaluated at each point
+    /// of the domain. The domain may be any sequence of scalars, e.g., the powers of a root of
+    /// unity. The polynomial can be recovered from the returned evaluations only if the domain
+    /// contains at least `t` distinct scalars.
+    pub fn recover_all_evals(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+        domain: &[C::ScalarType],
+    ) -> FastCryptoResult<Vec<C>> {
+        let shares = shares.map(|s| s.borrow().clone()).collect::<Vec<_>>();
+        let p = Self::interpolate(t, &shares)?;
+        Ok(domain.iter().map(|x| p.eval_at_scalar(x)).collect())
+    }
+
+    /// Given exactly `t` polynomial evaluations, it will recover the whole polynomial (of degree
+    /// t-1) and evaluate it at the given new indices, e.g., to issue shares to parties that join
+    /// the committee. Returns [FastCryptoError::InvalidInput] if the number of evaluations is not
+    /// `t` or if an index is duplicated.
+    pub fn recover_all_then_eval(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+        new_indices: &[ShareIndex],
+    ) -> FastCryptoResult<(Self, Vec<Eval<C>>)> {
+        let shares = shares.map(|s| s.borrow().clone()).collect::<Vec<_>>();
+        let p = Self::interpolate(t, &shares)?;
+        let evals = new_indices.iter().map(|i| p.eval(*i)).collect();
+        Ok((p, evals))
+    }
+
+    /// Lagrange interpolation of the coefficients of the polynomial of degree t-1 through exactly
+    /// `t` evaluations, i.e., p(x) = \sum_j y_j N(x) / ((x - x_j) N'(x_j)) for
+    /// N(x) = \prod_k (x - x_k).
+    fn interpolate(t: u32, shares: &[Eval<C>]) -> FastCryptoResult<Self> {
+        let t = t as usize;
+        if t == 0
+            || shares.len() != t
+            || shares.iter().map(|s| s.index).collect::<HashSet<_>>().len() != t
+        {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let xs = shares
+            .iter()
+            .map(|s| C::ScalarType::from(s.index.get().into()))
+            .collect::<Vec<_>>();
+
+        let mut master = vec![C::ScalarType::generator()];
+        for x_k in &xs {
+            let mut next = vec![C::ScalarType::zero(); master.len() + 1];
+            for (m, c) in master.iter().enumerate() {
+                next[m + 1] += *c;
+                next[m] -= *c * *x_k;
+            }
+            master = next;
+        }
+        let denominators = xs
+            .iter()
+            .enumerate()
+            .map(|(j, x_j)| {
+                xs.iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != j)
+                    .fold(C::ScalarType::generator(), |acc, (_, x_k)| {
+                        acc * (*x_j - *x_k)
+                    })
+            })
+            .collect::<Vec<_>>();
+        let inv_denominators = batch_inverse(&denominators)?;
+
+        let mut coeffs = vec![C::zero(); t];
+        let mut quotient = vec![C::ScalarType::zero(); t];
+        for ((share, x_j), inv_denominator) in shares.iter().zip(&xs).zip(&inv_denominators) {
+            // Synthetic division of N(x) by (x - x_j).
+            quotient[t - 1] = master[t];
+            for k in (1..t).rev() {
+                quotient[k - 1] = master[k] + *x_j * quotient[k];
+            }
+            for (c, q) in coeffs.iter_mut().zip(&quotient) {
+                *c += share.value * (*q * *inv_denominator);
+            }
+        }
+        Ok(Self::from(coeffs))
+    }
+
+    /// Returns the low-degree extension of the given shares, i.e., the evaluations at the indices
+    /// 1, ..., `extended_domain` of the polynomial of degree t-1 through the `t` shares selected by
+    /// [Poly::select_subset], e.g., to commit to for sampling-based data availability checks. The
+    /// result does not depend on the order of the shares. The remaining shares are consistent with
+    /// the polynomial if and only if they equal the corresponding evaluations.
+    /// Returns [FastCryptoError::NotEnoughInputs] if there are less than `t` shares, and
+    /// [FastCryptoError::InvalidInput] if the domain does not include the index of every share or
+    /// if an index is duplicated.
+    pub fn lde_commitment(
+        shares: &[Eval<C>],
+        t: u32,
+        extended_domain: u32,
+    ) -> FastCryptoResult<Vec<C>> {
+        if shares.len() < t as usize {
+            return Err(FastCryptoError::NotEnoughInputs);
+        }
+        if shares.iter().any(|s| s.index.get() > extended_domain)
+            || shares.iter().map(|s| s.index).unique().count() != shares.len()
+        {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let subset = Self::select_subset(t, shares.iter())?;
+        let domain = Self::index_scalars(extended_domain);
+        Self::recover_all_evals(t, subset.iter(), &domain)
+    }
+
+    /// Selects `t` of the given polynomial evaluations in a canonical way, independently of their
+    /// order: The evaluations with the `t` smallest unique indices are returned, sorted by index.
+    /// An index may appear more than once only with the same value. Returns
+    /// [FastCryptoError::InvalidInput] if two evaluations have the same index but different values
+    /// (since which one is used would depend on the order), and [FastCryptoError::NotEnoughInputs]
+    /// if there are less than `t` unique indices.
+    pub fn select_subset(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+    ) -> FastCryptoResult<Vec<Eval<C>>> {
+        let subset = Self::sorted_unique(shares)?
+            .into_iter()
+            .take(t as usize)
+            .collect::<Vec<_>>();
+        if subset.len() < t as usize {
+            return Err(FastCryptoError::NotEnoughInputs);
+        }
+        Ok(subset)
+    }
+
+    /// Returns the given evaluations sorted by index without duplicates, or
+    /// [FastCryptoError::InvalidInput] if an index appears with different values.
+    fn sorted_unique(
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+    ) -> FastCryptoResult<Vec<Eval<C>>> {
+        let mut unique = BTreeMap::new();
+        for s in shares {
+            let s = s.borrow();
+            if *unique.entry(s.index).or_insert(s.value) != s.value {
+                return Err(FastCryptoError::InvalidInput); // conflicting values
+            }
+        }
+        Ok(unique
+            .into_iter()
+            .map(|(index, value)| Eval { index, value })
+            .collect())
+    }
+
+    /// Selects `t` of the given polynomial evaluations such that the difference between the
+    /// largest and the smallest selected index is minimal. Small differences between the indices
+    /// reduce the number of multiplications in the computation of the Lagrange coefficients that
+    /// do not fit in a u128 and thus fall back to scalar multiplications, see [Poly::fast_mult].
+    /// If there are multiple such subsets, the one with the smallest indices is returned, sorted
+    /// by index. Duplicates and errors are handled as in [Poly::select_subset].
+    pub fn select_subset_min_spread(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+    ) -> FastCryptoResult<Vec<Eval<C>>> {
+        let sorted = Self::sorted_unique(shares)?;
+        if t == 0 || sorted.len() < t as usize {
+            return Err(FastCryptoError::NotEnoughInputs);
+        }
+        let window = sorted
+            .windows(t as usize)
+            .min_by_key(|w| w[w.len() - 1].index.get() - w[0].index.get())
+            .expect("at least one window");
+        Ok(window.to_vec())
+    }
+
+    /// Given at least `t` polynomial evaluations, it will recover the polynomial's constant term
+    /// using the `t` evaluations selected by [Poly::select_subset]. Also returns the number of
+    /// surplus unique evaluations, e.g., to decide whether a retry with error correction is
+    /// feasible.
+    pub fn recover_c0_with_slack(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+    ) -> FastCryptoResult<(C, u32)> {
+        Self::recover_c0_with_slack_and_selection(t, shares, SubsetSelection::SmallestIndices)
+    }
+
+    /// Same as [Poly::recover_c0_with_slack], but with the given strategy for selecting the `t`
+    /// evaluations used for reconstruction. The result does not depend on the strategy.
+    pub fn recover_c0_with_slack_and_selection(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+        selection: SubsetSelection,
+    ) -> FastCryptoResult<(C, u32)> {
+        let shares = shares.map(|s| s.borrow().clone()).collect::<Vec<_>>();
+        let subset = match selection {
+            SubsetSelection::SmallestIndices => Self::select_subset(t, shares.iter())?,
+            SubsetSelection::MinimalSpread => Self::select_subset_min_spread(t, shares.iter())?,
+        };
+        let slack = (shares.iter().map(|s| s.index).unique().count() - t as usize) as u32;
+        let c0 = Self::recover_c0(t, subset.iter())?;
+        Ok((c0, slack))
+    }
+
+    /// Given at least `t` polynomial evaluations keyed by their index, it will recover the
+    /// polynomial's constant term using the first `t` entries, i.e., the ones with the smallest
+    /// indices as in [Poly::select_subset]. The keys of a map are unique, thus no duplicate check
+    /// is needed.
+    pub fn recover_c0_from_map(t: u32, shares: &BTreeMap<ShareIndex, C>) -> FastCryptoResult<C> {
+        if shares.len() < t as usize {
+            return Err(FastCryptoError::NotEnoughInputs);
+        }
+        let shares = shares.iter().take(t as usize);
+        let indices = shares.clone().map(|(i, _)| i.get() as u128).collect_vec();
+        let coeffs = Self::get_lagrange_coefficients_for_c0_from_indices(&indices, None);
+        Ok(coeffs
+            .iter()
+            .zip(shares)
+            .fold(C::zero(), |acc, (c, (_, s))| acc + (*s * *c)))
+    }
+
+    /// Returns the polynomial whose coefficients are recovered with [Poly::recover_c0] from the
+    /// given evaluations, one set per coefficient, e.g., in a hierarchical secret sharing where
+    /// each coefficient is shared. Returns [FastCryptoError::InvalidInput] if no sets are given or
+    /// if a set does not consist of exactly `t` evaluations with unique indices.
+    pub fn from_reconstructed_coefficients(
+        sub_shares_per_coeff: &[Vec<Eval<C>>],
+        t: u32,
+    ) -> FastCryptoResult<Self> {
+        if sub_shares_per_coeff.is_empty()
+            || sub_shares_per_coeff
+                .iter()
+                .any(|shares| shares.len() != t as usize)
+        {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        sub_shares_per_coeff
+            .iter()
+            .map(|shares| Self::recover_c0(t, shares.iter()))
+            .collect::<FastCryptoResult<Vec<_>>>()
+            .map(Self::from)
+    }
+
+    /// Returns the unique polynomial of degree `2m-1` that matches both the given values and
+    /// derivatives at the `m` given points, where each point is a triple (x, P(x), P'(x)), e.g., for
+    /// Birkhoff secret sharing where a party holds both a share and a derivative share. Uses Newton
+    /// divided differences with each point repeated twice. Returns [FastCryptoError::InvalidInput]
+    /// if no points are given or if the x-coordinates are not unique.
+    pub fn hermite_interpolate(points: &[(C::ScalarType, C, C)]) -> FastCryptoResult<Self> {
+        if points.is_empty()
+            || points
+                .iter()
+                .enumerate()
+                .any(|(i, (x, _, _))| points[..i].iter().any(|(y, _, _)| x == y))
+        {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let nodes = points.iter().flat_map(|(x, _, _)| [*x, *x]).collect_vec();
+
+        // After round k, diffs[i] = f[z_{i-k}, ..., z_i] for all i >= k. For a repeated node, the
+        // first order divided difference is the derivative.
+        let mut diffs = points.iter().flat_map(|(_, v, _)| [*v, *v]).collect_vec();
+        let mut newton = vec![diffs[0]];
+        for k in 1..nodes.len() {
+            for i in (k..nodes.len()).rev() {
+                diffs[i] = if k == 1 && i % 2 == 1 {
+                    points[i / 2].2
+                } else {
+                    ((diffs[i] - diffs[i - 1]) / (nodes[i] - nodes[i - k]))?
+                };
+            }
+            newton.push(diffs[k]);
+        }
+
+        // Convert from the Newton basis to the monomial basis using Horner's method.
+        let mut coeffs = vec![newton[nodes.len() - 1]];
+        for k in (0..nodes.len() - 1).rev() {
+            let mut next = vec![C::zero(); coeffs.len() + 1];
+            for (j, c) in coeffs.iter().enumerate() {
+                next[j + 1] += *c;
+                next[j] -= *c * nodes[k];
+            }
+            next[0] += newton[k];
+            coeffs = next;
+        }
+        Ok(Self::from(coeffs))
+    }
+
     /// Checks if a given share is valid.
     pub fn verify_share(&self, idx: ShareIndex, share: &C::ScalarType) -> FastCryptoResult<()> {
+        self.verify_share_returning_pub(idx, share).map(|_| ())
+    }
+
+    /// Checks if a given share is valid and returns the corresponding public share
+    /// `generator * share` on success.
+    pub fn verify_share_returning_pub(
+        &self,
+        idx: ShareIndex,
+        share: &C::ScalarType,
+    ) -> FastCryptoResult<C> {
         let e = C::generator() * share;
-        let pub_eval = self.eval(idx);
-        if pub_eval.value == e {
+        if self.eval(idx).value == e {
+            Ok(e)
+        } else {
+            Err(FastCryptoError::InvalidInput)
+        }
+    }
+
+    /// Checks that the polynomial evaluates to `claimed_pk` at zero, e.g., that a public polynomial
+    /// commits to a claimed public key. Since 0 is not a valid [ShareIndex], this cannot be done
+    /// with [Poly::eval], but the evaluation at zero is simply the constant term. Returns
+    /// [FastCryptoError::InvalidInput] if the check fails or if the polynomial has no coefficients.
+    pub fn verify_secret_commitment(&self, claimed_pk: &C) -> FastCryptoResult<()> {
+        if self.c0_checked()? == claimed_pk {
             Ok(())
         } else {
             Err(FastCryptoError::InvalidInput)
         }
     }
 
-    /// Return the constant term of the polynomial.
-    pub fn c0(&self) -> &C {
-        &self.0[0]
+    /// Checks that the k-th coefficient of the public polynomial commits to the claimed scalar,
+    /// i.e., that it equals generator * claimed, e.g., when a dealer reveals a coefficient to
+    /// resolve a complaint. Returns [FastCryptoError::InvalidInput] if k is larger than the degree
+    /// or if the check fails.
+    pub fn verify_coefficient(&self, k: u32, claimed: &C::ScalarType) -> FastCryptoResult<()> {
+        match self.0.get(k as usize) {
+            Some(c) if *c == C::generator() * claimed => Ok(()),
+            _ => Err(FastCryptoError::InvalidInput),
+        }
     }
 
-    /// Returns the coefficients of the polynomial.
-    pub fn as_vec(&self) -> &Vec<C> {
-        &self.0
+    /// Checks if the given public key shares (i.e., G*p(i)) are consistent with the polynomial.
+    /// Returns an error on the first inconsistent share.
+    pub fn verify_public_shares(&self, shares: &[Eval<C>]) -> FastCryptoResult<()> {
+        if shares.iter().all(|s| self.eval(s.index).value == s.value) {
+            Ok(())
+        } else {
+            Err(FastCryptoError::InvalidInput)
+        }
     }
-}
 
-/// Scalar operations.
+    /// Returns the indices of all public key shares that are inconsistent with the polynomial.
+    pub fn find_invalid_public_shares(&self, shares: &[Eval<C>]) -> Vec<ShareIndex> {
+        shares
+            .iter()
+            .filter(|s| self.eval(s.index).value != s.value)
+            .map(|s| s.index)
+            .collect()
+    }
 
-impl<C: Scalar> Poly<C> {
-    /// Returns a new polynomial of the given degree where each coefficients is
-    /// sampled at random from the given RNG.
-    /// In the context of secret sharing, the threshold is the degree + 1.
-    pub fn rand<R: AllowedRng>(degree: u32, rng: &mut R) -> Self {
-        let coeffs: Vec<C> = (0..=degree).map(|_| C::rand(rng)).collect();
-        Self::from(coeffs)
+    /// Checks that a claimed difference of two shares, see [IndexedValue::sub], is consistent with
+    /// the difference of this and the `other` public polynomial.
+    pub fn verify_share_difference(
+        &self,
+        other: &Self,
+        diff: &Eval<C::ScalarType>,
+    ) -> FastCryptoResult<()> {
+        if self.eval(diff.index).value - other.eval(diff.index).value == C::generator() * diff.value
+        {
+            Ok(())
+        } else {
+            Err(FastCryptoError::InvalidInput)
+        }
     }
 
-    /// Commits the scalar polynomial to the group and returns a polynomial over
-    /// the group.
-    pub fn commit<P: GroupElement<ScalarType = C>>(&self) -> Poly<P> {
-        let commits = self
+    /// Checks that all the coefficients above `max_degree` are zero, e.g., that a dealer did not
+    /// commit to a polynomial of a higher degree than agreed.
+    pub fn verify_max_degree(&self, max_degree: u32) -> FastCryptoResult<()> {
+        if self
             .0
             .iter()
-            .map(|c| P::generator() * c)
-            .collect::<Vec<P>>();
+            .skip(max_degree as usize + 1)
+            .all(|c| *c == C::zero())
+        {
+            Ok(())
+        } else {
+            Err(FastCryptoError::InvalidInput)
+        }
+    }
 
-        Poly::<P>::from(commits)
+    /// Returns the degree of the polynomial ignoring leading zero coefficients, e.g., after
+    /// [Poly::add] of polynomials whose top coefficients cancel out.
+    pub fn effective_degree(&self) -> u32 {
+        self.0.iter().rposition(|c| *c != C::zero()).unwrap_or(0) as u32
     }
-}
 
-impl<C: GroupElement + MultiScalarMul> Poly<C> {
-    /// Given exactly `t` polynomial evaluations, it will recover the polynomial's
+    /// Returns the constant term if the polynomial is a constant (i.e., its effective degree is
+    /// zero), and [FastCryptoError::InvalidInput] otherwise or if it has no coefficients.
+    pub fn try_into_constant(self) -> FastCryptoResult<C> {
+        if self.effective_degree() != 0 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        self.0.first().copied().ok_or(FastCryptoError::InvalidInput)
+    }
+
+    /// Consumes the polynomial and returns only its constant term and degree, e.g., for compact
+    /// long-term storage of a public polynomial after the dealing has been verified. Note that the
+    /// full polynomial is needed to verify shares, so this should only be used once that is no
+    /// longer required. Panics if the polynomial has no coefficients, see [Poly::degree].
+    pub fn skeleton(self) -> (C, u32) {
+        let degree = self.degree();
+        (self.0[0], degree)
+    }
+
+    /// Return the constant term of the polynomial. Panics if the polynomial has no coefficients,
+    /// see [Poly::degree]. Use [Poly::c0_checked] for such polynomials.
+    pub fn c0(&self) -> &C {
+        &self.0[0]
+    }
+
+    /// Same as [Poly::c0], but returns [FastCryptoError::InvalidInput] instead of panicking if the
+    /// polynomial has no coefficients.
+    pub fn c0_checked(&self) -> FastCryptoResult<&C> {
+        self.0.first().ok_or(FastCryptoError::InvalidInput)
+    }
+
+    /// Returns the i-th coefficient of the polynomial as `self[i]`, but returns
+    /// [FastCryptoError::InvalidInput] instead of panicking if i > degree().
+    pub fn coefficient_checked(&self, i: usize) -> FastCryptoResult<&C> {
+        self.0.get(i).ok_or(FastCryptoError::InvalidInput)
+    }
+
+    /// Returns the coefficients of the polynomial.
+    pub fn as_vec(&self) -> &Vec<C> {
+        &self.0
+    }
+
+    /// Copies the coefficients of the polynomial into `dest`, reusing the allocation of `dest`'s
+    /// coefficients if its capacity suffices, e.g., for scratch space in hot loops.
+    pub fn clone_into(&self, dest: &mut Poly<C>) {
+        dest.invalidate_cache();
+        dest.0.clone_from(&self.0);
+    }
+
+    /// Returns the coefficients of the polynomial for in-place editing. The number of
+    /// coefficients, and thus the degree, cannot be changed.
+    pub fn coefficients_mut(&mut self) -> &mut [C] {
+        self.invalidate_cache();
+        &mut self.0
+    }
+
+    /// Returns the `k` coefficients of the highest degrees, ordered by degree as in
+    /// [Poly::as_vec], e.g., for proving that they are zero. Returns
+    /// [FastCryptoError::InvalidInput] if `k` is larger than the number of coefficients.
+    pub fn high_coefficients(&self, k: u32) -> FastCryptoResult<&[C]> {
+        let start = self
+            .0
+            .len()
+            .checked_sub(k as usize)
+            .ok_or(FastCryptoError::InvalidInput)?;
+        Ok(&self.0[start..])
+    }
+
+    /// Returns the polynomials P_even and P_odd with the even and odd coefficients of P,
+    /// respectively, such that P(x) = P_even(x^2) + x * P_odd(x^2), e.g., for radix-2 evaluation.
+    /// If P is constant, P_odd is the zero polynomial.
+    pub fn split_even_odd(&self) -> (Self, Self) {
+        let even = self.0.iter().step_by(2).copied().collect::<Vec<_>>();
+        let odd = self
+            .0
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .copied()
+            .collect::<Vec<_>>();
+        let odd = if odd.is_empty() {
+            Self::zero()
+        } else {
+            Self::from(odd)
+        };
+        (Self::from(even), odd)
+    }
+
+    /// Returns the weighted sum \sum w_i c_i of the coefficients c_i of the polynomial with the
+    /// given weights w_i. The number of weights must be equal to the number of coefficients.
+    pub fn inner_product(&self, weights: &[C::ScalarType]) -> FastCryptoResult<C> {
+        if weights.len() != self.0.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Ok(self
+            .0
+            .iter()
+            .zip(weights)
+            .fold(C::zero(), |acc, (c, w)| acc + *c * w))
+    }
+
+    /// Returns the constant terms of the given polynomials, e.g., the contribution of each dealer
+    /// to the aggregated public key of a DKG. Returns [FastCryptoError::InvalidInput] if a
+    /// polynomial has no coefficients.
+    pub fn contributions(dealings: &[Self]) -> FastCryptoResult<Vec<C>> {
+        dealings.iter().map(|p| p.c0_checked().copied()).collect()
+    }
+
+    /// Returns the sum of the constant terms of the given polynomials, which equals the constant
+    /// term of their aggregate (sum). Returns [FastCryptoError::InvalidInput] if a polynomial has
+    /// no coefficients.
+    pub fn aggregate_key(dealings: &[Self]) -> FastCryptoResult<C> {
+        dealings
+            .iter()
+            .try_fold(C::zero(), |acc, p| Ok(acc + *p.c0_checked()?))
+    }
+
+    /// Returns the aggregate public polynomial of a DKG, i.e., the sum of the public polynomials of
+    /// the qualified dealers, together with its constant term, the group public key. Returns
+    /// [FastCryptoError::InvalidInput] if there are no dealings, if their degrees differ or if they
+    /// have no coefficients.
+    pub fn finalize_dkg(dealings: &[PublicPoly<C>]) -> FastCryptoResult<(PublicPoly<C>, C)> {
+        let first = dealings.first().ok_or(FastCryptoError::InvalidInput)?;
+        if dealings.iter().any(|d| d.0.len() != first.0.len()) {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let mut aggregate = first.clone();
+        dealings[1..].iter().for_each(|d| aggregate.add(d));
+        let pk = *aggregate.c0_checked()?;
+        Ok((aggregate, pk))
+    }
+
+    /// Returns the sum of the shares a party received from the qualified dealers of a DKG, which
+    /// is its final share of the aggregated private polynomial.
+    pub fn aggregate_shares(received: &[C::ScalarType]) -> C::ScalarType {
+        received
+            .iter()
+            .fold(C::ScalarType::zero(), |acc, s| acc + *s)
+    }
+
+    /// Checks that the final share of a party of a DKG (see [Poly::aggregate_shares]) is
+    /// consistent with the aggregated public polynomial, i.e., the sum of the public polynomials
+    /// of the qualified dealers. This is [Poly::verify_share] on the aggregate.
+    pub fn verify_aggregate_share(
+        aggregate_public: &PublicPoly<C>,
+        my_index: ShareIndex,
+        my_aggregate_share: &C::ScalarType,
+    ) -> FastCryptoResult<()> {
+        aggregate_public.verify_share(my_index, my_aggregate_share)
+    }
+
+    /// Checks a resharing of the secret of `old_public` in which the party with index
+    /// `old_indices[j]` shared its old share with the polynomial committed to in
+    /// `reshare_publics[j]`: The constant term of each reshare polynomial must be the public share
+    /// of its dealer. This implies that the Lagrange-weighted sum of the reshare polynomials, which
+    /// is the new public polynomial, has the old secret in the exponent as its constant term, since
+    /// its constant term is the interpolation of the old public shares at zero. The per-dealer
+    /// check is stronger since it also rejects resharings where the errors of several dealers
+    /// cancel out in the sum.
+    ///
+    /// Returns [FastCryptoError::InvalidInput] if the lengths do not match, if there are fewer
+    /// old parties than the old threshold, if an old index is duplicated or if a polynomial has no
+    /// coefficients, and [FastCryptoError::InvalidProof] if the check fails.
+    pub fn verify_resharing(
+        old_public: &PublicPoly<C>,
+        reshare_publics: &[PublicPoly<C>],
+        old_indices: &[ShareIndex],
+    ) -> FastCryptoResult<()> {
+        if reshare_publics.len() != old_indices.len()
+            || old_indices.len() < old_public.0.len()
+            || old_indices.iter().collect::<HashSet<_>>().len() != old_indices.len()
+        {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        old_public.degree_checked()?;
+        let reshared = old_indices
+            .iter()
+            .zip(reshare_publics)
+            .map(|(index, reshare)| {
+                Ok(Eval {
+                    index: *index,
+                    value: *reshare.c0_checked()?,
+                })
+            })
+            .collect::<FastCryptoResult<Vec<_>>>()?;
+        if reshared.iter().any(|e| old_public.eval(e.index) != *e) {
+            return Err(FastCryptoError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Checks that the sub-shares received from a party that reshared its share are consistent
+    /// with the public polynomial of the resharing, using [Poly::verify_share] for each sub-share.
+    /// Returns [FastCryptoError::InvalidInput] if a sub-share is invalid.
+    pub fn verify_reshare_subshares(
+        reshare_public: &PublicPoly<C>,
+        subshares: &[Eval<C::ScalarType>],
+    ) -> FastCryptoResult<()> {
+        subshares
+            .iter()
+            .try_for_each(|s| reshare_public.verify_share(s.index, &s.value))
+    }
+
+    /// Checks that a chain of public polynomials, one per epoch of a committee that reshares its
+    /// secret every epoch, all commit to the same secret, i.e., that consecutive polynomials have
+    /// the same constant term. Returns [FastCryptoError::InvalidInput] if the chain is empty or if
+    /// a polynomial has no coefficients, and
+    /// a [FastCryptoError::GeneralError] naming the first epoch that changed the secret otherwise.
+    pub fn verify_reshare_chain(epoch_publics: &[PublicPoly<C>]) -> FastCryptoResult<()> {
+        if epoch_publics.is_empty() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let c0s = epoch_publics
+            .iter()
+            .map(|p| p.c0_checked())
+            .collect::<FastCryptoResult<Vec<_>>>()?;
+        match c0s.windows(2).position(|pair| pair[0] != pair[1]) {
+            Some(i) => Err(FastCryptoError::GeneralError(format!(
+                "epoch {} changed the secret",
+                i + 1
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks partial decryptions d_i = u^{s_i} of a ciphertext component u against the
+    /// verification keys vk_i = h^{s_i} of the parties, where h is the generator of this group,
+    /// using e(d_i, h) = e(u, vk_i). Returns the validity of each share, e.g., to filter out bad
+    /// shares before combining the valid ones. Returns [FastCryptoError::InvalidInput] if the
+    /// verification keys and the shares do not have the same indices in the same order.
+    pub fn verify_decryption_shares<G: Pairing<Other = C>>(
+        vks: &[Eval<C>],
+        shares: &[Eval<G>],
+        ciphertext_component: &G,
+    ) -> FastCryptoResult<Vec<bool>>
+    where
+        <G as Pairing>::Output: PartialEq,
+    {
+        if vks.len() != shares.len() || vks.iter().zip(shares).any(|(vk, d)| vk.index != d.index) {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Ok(vks
+            .iter()
+            .zip(shares)
+            .map(|(vk, d)| {
+                d.value.pairing(&C::generator()) == ciphertext_component.pairing(&vk.value)
+            })
+            .collect())
+    }
+}
+
+/// Returns the inverses of the given scalars using Montgomery's trick, i.e., with a single field
+/// inversion and about 3n multiplications instead of n inversions. Returns
+/// [FastCryptoError::InvalidInput] if one of the scalars is zero.
+pub(crate) fn batch_inverse<S: Scalar>(values: &[S]) -> FastCryptoResult<Vec<S>> {
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+    // prefix_products[i] = values[0] * ... * values[i].
+    let prefix_products = values
+        .iter()
+        .scan(S::generator(), |acc, v| {
+            *acc = *acc * *v;
+            Some(*acc)
+        })
+        .collect::<Vec<_>>();
+    let mut inverse = prefix_products[values.len() - 1].inverse()?;
+    let mut inverses = vec![S::zero(); values.len()];
+    for i in (1..values.len()).rev() {
+        // inverse = (values[0] * ... * values[i])^{-1}
+        inverses[i] = inverse * prefix_products[i - 1];
+        inverse = inverse * values[i];
+    }
+    inverses[0] = inverse;
+    Ok(inverses)
+}
+
+/// Returns how many more distinct shares are needed, given `k` distinct shares, to recover the
+/// secret of a sharing with threshold `t` while correcting up to `e` incorrect shares, i.e.,
+/// t + 2e - k or zero if there are enough shares already. See [Poly::recover_c0_robust].
+pub fn shares_needed_for_robustness(k: u32, t: u32, e: u32) -> u32 {
+    t.saturating_add(e.saturating_mul(2)).saturating_sub(k)
+}
+
+#[cfg(feature = "rayon")]
+impl<C: GroupElement + Send + Sync> Poly<C> {
+    /// Performs polynomial addition in place, processing the coefficients in parallel.
+    pub fn add_par(&mut self, other: &Self) {
+        self.invalidate_cache();
+        // if we have a smaller degree we should pad with zeros
+        if self.0.len() < other.0.len() {
+            self.0.resize(other.0.len(), C::zero())
+        }
+        self.0
+            .par_iter_mut()
+            .zip(other.0.par_iter())
+            .for_each(|(a, b)| *a += *b)
+    }
+}
+
+impl<C: GroupElement> PublicKeyShares<C> {
+    /// Evaluates the given public polynomial at the indices 1, ..., n.
+    pub fn new(public_poly: &PublicPoly<C>, n: u32) -> Self {
+        Self(
+            (1..=n)
+                .map(|i| public_poly.eval(ShareIndex::new(i).expect("nonzero")).value)
+                .collect(),
+        )
+    }
+
+    /// Returns the public key share of index i, or None if i > n.
+    pub fn get(&self, i: ShareIndex) -> Option<&C> {
+        self.0.get(i.get() as usize - 1)
+    }
+}
+
+impl<C: GroupElement> C0Recovery<C> {
+    pub fn new(t: u32) -> Self {
+        Self {
+            t,
+            shares: BTreeMap::new(),
+            conflicting: false,
+        }
+    }
+
+    /// Adds a share. Shares that were already added are ignored. Returns
+    /// [FastCryptoError::InvalidInput] if a share with the same index but a different value was
+    /// added before, in which case [C0Recovery::finalize] will fail too.
+    pub fn add(&mut self, share: Eval<C>) -> FastCryptoResult<()> {
+        if *self.shares.entry(share.index).or_insert(share.value) != share.value {
+            self.conflicting = true;
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Returns the number of distinct shares added so far.
+    pub fn len(&self) -> usize {
+        self.shares.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shares.is_empty()
+    }
+
+    /// Returns true if enough shares were added to recover the constant term.
+    pub fn is_complete(&self) -> bool {
+        self.shares.len() >= self.t as usize
+    }
+
+    /// Recovers the constant term from the `t` shares with the smallest indices as
+    /// [Poly::recover_c0_from_map]. Returns [FastCryptoError::InvalidInput] if conflicting shares
+    /// were added, and [FastCryptoError::NotEnoughInputs] if less than `t` shares were added.
+    pub fn finalize(&self) -> FastCryptoResult<C> {
+        if self.conflicting {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Poly::recover_c0_from_map(self.t, &self.shares)
+    }
+}
+
+impl<C: GroupElement> Extend<Eval<C>> for C0Recovery<C> {
+    /// Adds the shares as [C0Recovery::add]. Conflicting shares are reported by
+    /// [C0Recovery::finalize].
+    fn extend<I: IntoIterator<Item = Eval<C>>>(&mut self, shares: I) {
+        shares.into_iter().for_each(|share| {
+            let _ = self.add(share);
+        });
+    }
+}
+
+impl<C: GroupElement + Serialize> MerklePoly<C> {
+    /// Builds the Merkle tree over the coefficients of the given polynomial.
+    pub fn new(poly: Poly<C>) -> Self {
+        let mut leaves = poly.0.iter().map(merkle_leaf).collect::<Vec<_>>();
+        leaves.resize(leaves.len().next_power_of_two(), MERKLE_PADDING);
+        let mut layers = vec![leaves];
+        while layers[layers.len() - 1].len() > 1 {
+            let next = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| merkle_node(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+        Self { poly, layers }
+    }
+
+    /// Returns the root of the Merkle tree bound to the degree of the polynomial, i.e., the
+    /// commitment to the coefficients. Panics if the polynomial has no coefficients, see
+    /// [Poly::degree].
+    pub fn root(&self) -> [u8; 32] {
+        merkle_root(self.poly.degree(), &self.layers[self.layers.len() - 1][0])
+    }
+
+    pub fn poly(&self) -> &Poly<C> {
+        &self.poly
+    }
+
+    /// Returns the authentication path of the k-th coefficient, or [FastCryptoError::InvalidInput]
+    /// if k is larger than the degree.
+    pub fn open(&self, k: u32) -> FastCryptoResult<MerklePath> {
+        if k > self.poly.degree_checked()? {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let k = k as usize;
+        Ok(MerklePath(
+            self.layers[..self.layers.len() - 1]
+                .iter()
+                .enumerate()
+                .map(|(level, layer)| layer[(k >> level) ^ 1])
+                .collect(),
+        ))
+    }
+
+    /// Evaluates the polynomial at i and returns the authentication paths of all coefficients,
+    /// which the verifier needs together with the coefficients, see [MerklePoly::verify_eval].
+    pub fn eval_with_paths(&self, i: ShareIndex) -> (Eval<C>, Vec<MerklePath>) {
+        let paths = (0..self.poly.0.len() as u32)
+            .map(|k| self.open(k).expect("k is at most the degree"))
+            .collect();
+        (self.poly.eval(i), paths)
+    }
+
+    /// Checks that `coefficient` is the k-th coefficient of the polynomial of the given degree
+    /// committed to by `root`. Returns [FastCryptoError::InvalidInput] if k is larger than the
+    /// degree or if the path does not have the length of the paths for this degree, and
+    /// [FastCryptoError::InvalidProof] if the path does not lead to the root.
+    pub fn verify_path(
+        root: &[u8; 32],
+        degree: u32,
+        k: u32,
+        coefficient: &C,
+        path: &MerklePath,
+    ) -> FastCryptoResult<()> {
+        let height = (degree as usize + 1).next_power_of_two().trailing_zeros() as usize;
+        if k > degree || path.0.len() != height {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let top =
+            path.0
+                .iter()
+                .enumerate()
+                .fold(merkle_leaf(coefficient), |node, (level, sibling)| {
+                    if (k >> level) & 1 == 0 {
+                        merkle_node(&node, sibling)
+                    } else {
+                        merkle_node(sibling, &node)
+                    }
+                });
+        if merkle_root(degree, &top) != *root {
+            return Err(FastCryptoError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Checks that `eval` is the evaluation of the polynomial committed to by `root`, given all of
+    /// its coefficients and their authentication paths as returned by [MerklePoly::eval_with_paths].
+    /// Returns [FastCryptoError::InvalidInput] if the numbers of coefficients and paths differ or
+    /// are zero, and [FastCryptoError::InvalidProof] if a path or the evaluation is invalid.
+    pub fn verify_eval(
+        root: &[u8; 32],
+        eval: &Eval<C>,
+        coefficients: &[C],
+        paths: &[MerklePath],
+    ) -> FastCryptoResult<()> {
+        if coefficients.is_empty() || coefficients.len() != paths.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let degree = (coefficients.len() - 1) as u32;
+        for (k, (coefficient, path)) in coefficients.iter().zip(paths).enumerate() {
+            Self::verify_path(root, degree, k as u32, coefficient, path)?;
+        }
+        if Poly::from(coefficients.to_vec()).eval(eval.index) != *eval {
+            return Err(FastCryptoError::InvalidProof);
+        }
+        Ok(())
+    }
+}
+
+fn merkle_leaf<C: Serialize>(coefficient: &C) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([MERKLE_LEAF_PREFIX]);
+    hasher.update(bcs::to_bytes(coefficient).expect("serialize should never fail"));
+    hasher.finalize().into()
+}
+
+fn merkle_root(degree: u32, top: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([MERKLE_ROOT_PREFIX]);
+    hasher.update(degree.to_le_bytes());
+    hasher.update(top);
+    hasher.finalize().into()
+}
+
+fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([MERKLE_NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+impl<C: GroupElement + Serialize> Poly<C> {
+    /// Serializes only the constant term of the polynomial (e.g., the group public key of a public
+    /// polynomial). Returns [FastCryptoError::InvalidInput] if the polynomial has no coefficients.
+    pub fn c0_bytes(&self) -> FastCryptoResult<Vec<u8>> {
+        Ok(bcs::to_bytes(self.c0_checked()?).expect("serialize should never fail"))
+    }
+
+    /// Returns a digest committing to the polynomial (e.g., a dealing) in the given session, to be
+    /// signed for replay protection. Same as [Poly::transcript_hash_with_tag] with the default tag
+    /// [TRANSCRIPT_HASH_DST].
+    pub fn transcript_hash(&self, session_id: &[u8]) -> [u8; 32] {
+        self.transcript_hash_with_tag(session_id, &DomainSeparationTag::new(TRANSCRIPT_HASH_DST))
+    }
+
+    /// Returns a digest committing to the polynomial in the given session, computed as
+    /// SHA3-256(tag_len | tag | session_id_len | session_id | bcs(self)) where lengths are
+    /// big-endian u64. The tag separates it from other uses of SHA3-256, while the length
+    /// prefixes make the encoding of the inputs unambiguous.
+    pub fn transcript_hash_with_tag(
+        &self,
+        session_id: &[u8],
+        tag: &DomainSeparationTag,
+    ) -> [u8; 32] {
+        let mut hasher = Sha3_256::default();
+        hasher.update((tag.as_bytes().len() as u64).to_be_bytes());
+        hasher.update(tag.as_bytes());
+        hasher.update((session_id.len() as u64).to_be_bytes());
+        hasher.update(session_id);
+        hasher.update(bcs::to_bytes(self).expect("serialize should never fail"));
+        hasher.finalize().into()
+    }
+
+    /// Returns a compact fingerprint of a set of shares, e.g., to log which shares were used for a
+    /// reconstruction. The fingerprint is SHA3-256(bcs(([SHARE_SET_FINGERPRINT_DST], pairs))) where
+    /// pairs are the (index, serialized value) pairs of the shares in sorted order, thus it does
+    /// not depend on the order of the shares.
+    pub fn share_set_fingerprint(shares: &[Eval<C>]) -> [u8; 32] {
+        let pairs = shares
+            .iter()
+            .map(|s| {
+                let value = bcs::to_bytes(&s.value).expect("serialize should never fail");
+                (s.index, value)
+            })
+            .sorted()
+            .collect::<Vec<_>>();
+        let mut hasher = Sha3_256::default();
+        hasher.update(
+            bcs::to_bytes(&(SHARE_SET_FINGERPRINT_DST, pairs))
+                .expect("serialize should never fail"),
+        );
+        hasher.finalize().into()
+    }
+}
+
+impl<C: GroupElement + DeserializeOwned> Poly<C> {
+    /// Deserializes a constant term that was serialized using [Poly::c0_bytes].
+    pub fn c0_from_bytes(bytes: &[u8]) -> FastCryptoResult<C> {
+        bcs::from_bytes(bytes).map_err(|_| FastCryptoError::InvalidInput)
+    }
+}
+
+impl<C: GroupElement> Poly<C> {
+    /// Returns the concatenation of the fixed-length encodings of the coefficients, which for
+    /// BLS12-381 group elements is the compressed point encoding. No length prefix is added.
+    ///
+    /// Note that the serde encoding of BLS12-381 group elements is already compressed, so this is
+    /// only shorter than the bcs encoding of the polynomial by the length prefix of the vector.
+    pub fn to_bytes_compressed<const N: usize>(&self) -> Vec<u8>
+    where
+        C: ToFromByteArray<N>,
+    {
+        self.0.iter().flat_map(|c| c.to_byte_array()).collect()
+    }
+
+    /// Inverse of [Poly::to_bytes_compressed]. Each coefficient is decoded with
+    /// [ToFromByteArray::from_byte_array], which for BLS12-381 group elements checks that the point
+    /// is on the curve and in the right subgroup. Returns [FastCryptoError::InvalidInput] if the
+    /// length of the input is not a positive multiple of N or if a coefficient is invalid.
+    pub fn from_bytes_compressed<const N: usize>(bytes: &[u8]) -> FastCryptoResult<Self>
+    where
+        C: ToFromByteArray<N>,
+    {
+        if bytes.is_empty() || bytes.len() % N != 0 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        bytes
+            .chunks_exact(N)
+            .map(|chunk| C::from_byte_array(chunk.try_into().expect("length is N")))
+            .collect::<FastCryptoResult<Vec<_>>>()
+            .map(Self::from)
+    }
+}
+
+/// Explicit byte orders for cross-language test vectors, see [IndexedValue::to_bytes_be]. The
+/// coefficients are concatenated without a length prefix.
+impl<C: Scalar> Poly<C> {
+    pub fn to_bytes_be<const N: usize>(&self) -> Vec<u8>
+    where
+        C: ToFromByteArray<N>,
+    {
+        self.0
+            .iter()
+            .flat_map(|c| scalar_to_bytes(c, true))
+            .collect()
+    }
+
+    pub fn to_bytes_le<const N: usize>(&self) -> Vec<u8>
+    where
+        C: ToFromByteArray<N>,
+    {
+        self.0
+            .iter()
+            .flat_map(|c| scalar_to_bytes(c, false))
+            .collect()
+    }
+
+    /// Inverse of [Poly::to_bytes_be]. Returns [FastCryptoError::InvalidInput] if the length is
+    /// not a positive multiple of N or if a coefficient is not canonical.
+    pub fn from_bytes_be<const N: usize>(bytes: &[u8]) -> FastCryptoResult<Self>
+    where
+        C: ToFromByteArray<N>,
+    {
+        Self::from_bytes_with_order(bytes, true)
+    }
+
+    /// Inverse of [Poly::to_bytes_le], see [Poly::from_bytes_be].
+    pub fn from_bytes_le<const N: usize>(bytes: &[u8]) -> FastCryptoResult<Self>
+    where
+        C: ToFromByteArray<N>,
+    {
+        Self::from_bytes_with_order(bytes, false)
+    }
+
+    fn from_bytes_with_order<const N: usize>(
+        bytes: &[u8],
+        big_endian: bool,
+    ) -> FastCryptoResult<Self>
+    where
+        C: ToFromByteArray<N>,
+    {
+        if bytes.is_empty() || bytes.len() % N != 0 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        bytes
+            .chunks_exact(N)
+            .map(|chunk| scalar_from_bytes(chunk.try_into().expect("length is N"), big_endian))
+            .collect::<FastCryptoResult<Vec<_>>>()
+            .map(Self::from)
+    }
+}
+
+impl<C: GroupElement + Serialize + DeserializeOwned> Poly<C> {
+    /// Checks that all coefficients are valid group elements (e.g., in the right subgroup) by
+    /// running the same checks as deserialization. Useful for polynomials that were constructed
+    /// from elements that were not validated, e.g., using
+    /// [fastcrypto::groups::FromTrustedByteArray].
+    pub fn validate_coefficients(&self) -> FastCryptoResult<()> {
+        self.0.iter().try_for_each(|c| {
+            let bytes = bcs::to_bytes(c).expect("serialize should never fail");
+            bcs::from_bytes::<C>(&bytes)
+                .map(|_| ())
+                .map_err(|_| FastCryptoError::InvalidInput)
+        })
+    }
+}
+
+impl<C> From<Poly<C>> for VersionedPoly<C> {
+    fn from(poly: Poly<C>) -> Self {
+        Self::V1(poly)
+    }
+}
+
+impl<C> VersionedPoly<C> {
+    pub fn into_poly(self) -> Poly<C> {
+        match self {
+            Self::V1(poly) => poly,
+        }
+    }
+}
+
+impl<C: GroupElement + Serialize + DeserializeOwned> VersionedPoly<C> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("serialize should never fail")
+    }
+
+    /// Deserializes a polynomial that was serialized using [VersionedPoly::to_bytes] with any
+    /// supported version. Returns [FastCryptoError::InvalidInput] if the version is unknown or the
+    /// encoding is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> FastCryptoResult<Self> {
+        bcs::from_bytes(bytes).map_err(|_| FastCryptoError::InvalidInput)
+    }
+}
+
+/// Scalar operations.
+
+impl<C: Scalar> Poly<C> {
+    /// Returns a new polynomial of the given degree where each coefficients is
+    /// sampled at random from the given RNG.
+    /// In the context of secret sharing, the threshold is the degree + 1.
+    pub fn rand<R: AllowedRng>(degree: u32, rng: &mut R) -> Self {
+        let coeffs: Vec<C> = (0..=degree).map(|_| C::rand(rng)).collect();
+        Self::from(coeffs)
+    }
+
+    /// Splits `secret` into `n` shares with indices `1..=n` such that any `t` of them recover it.
+    ///
+    /// A threshold of 1 gives every party the secret itself (the polynomial is constant), so it
+    /// is rejected with [FastCryptoError::InvalidInput] unless `allow_trivial` is set, e.g., for
+    /// tests or single-party deployments.
+    pub fn split<R: AllowedRng>(
+        secret: C,
+        t: u32,
+        n: u32,
+        allow_trivial: bool,
+        rng: &mut R,
+    ) -> FastCryptoResult<Vec<Eval<C>>> {
+        if t == 0 || t > n || (t == 1 && !allow_trivial) {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let mut poly = Self::rand(t - 1, rng);
+        poly.0[0] = secret;
+        Ok((1..=n)
+            .map(|i| poly.eval(ShareIndex::new(i).expect("i > 0")))
+            .collect())
+    }
+
+    /// Returns a new polynomial with exactly `len` coefficients (i.e., of degree `len - 1`) where
+    /// each coefficient is sampled at random from the given RNG.
+    /// In the context of secret sharing, `len` is the threshold.
+    pub fn rand_with_len<R: AllowedRng>(len: usize, rng: &mut R) -> FastCryptoResult<Self> {
+        if len == 0 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let coeffs: Vec<C> = (0..len).map(|_| C::rand(rng)).collect();
+        Ok(Self::from(coeffs))
+    }
+
+    /// Returns a new polynomial of the given degree with a zero constant term and all other
+    /// coefficients sampled at random from the given RNG. Adding it to a sharing re-randomizes the
+    /// shares without changing the secret.
+    pub fn rand_zero_c0<R: AllowedRng>(degree: u32, rng: &mut R) -> Self {
+        let mut p = Self::rand(degree, rng);
+        p.0[0] = C::zero();
+        p
+    }
+
+    /// Returns a new polynomial of the given degree with exactly `nonzero_count` non-zero
+    /// coefficients sampled at random from the given RNG, one of which is the constant term and the
+    /// others at random positions. Note that the degree of the result can thus be smaller than
+    /// `degree` while the number of coefficients is always degree + 1. Returns
+    /// [FastCryptoError::InvalidInput] if `nonzero_count` is zero or larger than degree + 1.
+    pub fn rand_sparse<R: AllowedRng>(
+        degree: u32,
+        nonzero_count: u32,
+        rng: &mut R,
+    ) -> FastCryptoResult<Self> {
+        if nonzero_count == 0 || nonzero_count as u64 > degree as u64 + 1 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let mut positions = (1..=degree as usize).collect::<Vec<_>>();
+        positions.shuffle(rng);
+        let mut coeffs = vec![C::zero(); degree as usize + 1];
+        for i in std::iter::once(0).chain(positions.into_iter().take(nonzero_count as usize - 1)) {
+            coeffs[i] = std::iter::repeat_with(|| C::rand(rng))
+                .find(|c| *c != C::zero())
+                .expect("infinite iterator");
+        }
+        Ok(Self::from(coeffs))
+    }
+
+    /// Checks that the sum of the shares of the given parties, i.e., \sum_{i in indices} p(i),
+    /// equals `expected`, e.g., as a lightweight integrity check against a publicly known value.
+    /// Returns [FastCryptoError::InvalidInput] if an index is duplicated or if the check fails.
+    pub fn verify_share_sum(&self, indices: &[ShareIndex], expected: &C) -> FastCryptoResult<()> {
+        if indices.iter().collect::<HashSet<_>>().len() != indices.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let sum = indices
+            .iter()
+            .fold(C::zero(), |acc, i| acc + self.eval(*i).value);
+        if sum != *expected {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Converts n additive shares (that sum to the secret) into a Shamir sharing of the same
+    /// secret, i.e., returns a random polynomial of degree n-1 whose constant term is the sum of
+    /// the additive shares, together with its evaluations at the indices 1, ..., n. Any n of the
+    /// returned shares recover the secret. This is done by a dealer that knows all additive shares,
+    /// and thus the secret. Returns [FastCryptoError::InvalidInput] if no shares are given.
+    pub fn additive_to_shamir<R: AllowedRng>(
+        additive: &[C],
+        rng: &mut R,
+    ) -> FastCryptoResult<(Self, Vec<Eval<C>>)> {
+        if additive.is_empty() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let n = additive.len() as u32;
+        let mut p = Self::rand(n - 1, rng);
+        p.0[0] = additive.iter().fold(C::zero(), |acc, s| acc + s);
+        let shares = (1..=n)
+            .map(|i| p.eval(ShareIndex::new(i).expect("nonzero")))
+            .collect();
+        Ok((p, shares))
+    }
+
+    /// Returns a new polynomial of the given degree with constant term `secret` whose evaluation at
+    /// `index` is `value`. The coefficients of degree 1, ..., degree - 1 are sampled at random from
+    /// the given RNG and the leading coefficient is then set to satisfy the second constraint.
+    /// Returns [FastCryptoError::InvalidInput] if `degree < 1` since two constraints cannot be met
+    /// by a constant polynomial.
+    pub fn rand_with_secret_and_fixed_share<R: AllowedRng>(
+        degree: u32,
+        secret: C,
+        (index, value): (ShareIndex, C),
+        rng: &mut R,
+    ) -> FastCryptoResult<Self> {
+        if degree < 1 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let mut p = Self::rand(degree - 1, rng);
+        p.0[0] = secret;
+        // Solve p(index) + c * index^degree = value for the leading coefficient c.
+        let x = C::from(index.get().into());
+        let x_pow_degree = (0..degree).fold(C::generator(), |acc, _| acc * x);
+        let leading = ((value - p.eval(index).value) / x_pow_degree)?;
+        p.invalidate_cache();
+        p.0.push(leading);
+        Ok(p)
+    }
+
+    /// Multiplies two shares of the same index locally, giving a share of the product of the two
+    /// secrets on a polynomial of degree `2(t-1)`, thus `2t-1` such shares are needed for the
+    /// reconstruction. To keep the threshold at `t`, the degree must then be reduced, e.g., by
+    /// resharing. Returns [FastCryptoError::InvalidInput] if the indices differ.
+    pub fn multiply_shares_local(a: &Eval<C>, b: &Eval<C>) -> FastCryptoResult<Eval<C>> {
+        if a.index != b.index {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Ok(Eval {
+            index: a.index,
+            value: a.value * b.value,
+        })
+    }
+
+    /// Commits the scalar polynomial to the group and returns a polynomial over
+    /// the group.
+    pub fn commit<P: GroupElement<ScalarType = C>>(&self) -> Poly<P> {
+        let commits = self
+            .0
+            .iter()
+            .map(|c| P::generator() * c)
+            .collect::<Vec<P>>();
+
+        let commitment = Poly::<P>::from(commits);
+        if cfg!(feature = "self_audit") {
+            self.audit_commitment(&commitment, &mut rand::thread_rng())
+                .expect("group arithmetic is inconsistent");
+        }
+        commitment
+    }
+
+    /// Checks that the given commitment is consistent with the scalar polynomial by evaluating
+    /// both at a few random points, in order to catch broken group implementations. Called by
+    /// [Poly::commit] if the `self_audit` feature is enabled.
+    pub fn audit_commitment<P: GroupElement<ScalarType = C>, R: AllowedRng>(
+        &self,
+        commitment: &Poly<P>,
+        rng: &mut R,
+    ) -> FastCryptoResult<()> {
+        const AUDIT_POINTS: usize = 3;
+        if commitment.0.len() != self.0.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        for _ in 0..AUDIT_POINTS {
+            let x = C::rand(rng);
+            if P::generator() * self.eval_at_scalar(&x) != commitment.eval_at_scalar(&x) {
+                return Err(FastCryptoError::GeneralError(
+                    "Commitment is inconsistent with the polynomial".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Commits the scalar polynomial and computes the shares of the parties with indices
+    /// 1, ..., n, each together with its public counterpart generator * share. Returns an error if
+    /// n is smaller than the threshold degree + 1.
+    pub fn deal_with_public<P: GroupElement<ScalarType = C>>(
+        &self,
+        n: u32,
+    ) -> FastCryptoResult<DealingWithPublic<C, P>> {
+        if (n as usize) < self.0.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let shares = (1..=n)
+            .map(|i| {
+                let share = self.eval(ShareIndex::new(i).expect("nonzero"));
+                let public_share = Eval {
+                    index: share.index,
+                    value: P::generator() * share.value,
+                };
+                (share, public_share)
+            })
+            .collect();
+        Ok((self.commit(), shares))
+    }
+
+    /// Commits the scalar polynomial and computes the (private) shares of the parties with indices
+    /// 1, ..., n by evaluating the scalar polynomial, e.g., for a dealer in a VSS. Contrary to
+    /// [Poly::deal_with_public], no public shares are computed; they can be derived by the
+    /// receivers from the commitment. Returns an error if n is smaller than the threshold
+    /// degree + 1.
+    pub fn deal_private_and_public<P: GroupElement<ScalarType = C>>(
+        &self,
+        n: u32,
+    ) -> FastCryptoResult<(PublicPoly<P>, Vec<Eval<C>>)> {
+        if (n as usize) < self.0.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let shares = (1..=n)
+            .map(|i| self.eval(ShareIndex::new(i).expect("nonzero")))
+            .collect();
+        Ok((self.commit(), shares))
+    }
+
+    /// Given pairs of (old index, new index), returns the evaluations of the polynomial at the new
+    /// indices.
+    ///
+    /// Note that the value of a share is bound to its index, thus simply renaming the index of an
+    /// existing [Eval] results in an invalid share (which will fail verification and break
+    /// reconstruction). Shares must be relabeled by re-evaluating the polynomial, which is why this
+    /// is only possible with the private polynomial.
+    pub fn relabel_by_reevaluation(
+        &self,
+        mapping: &[(ShareIndex, ShareIndex)],
+    ) -> FastCryptoResult<Vec<Eval<C>>> {
+        let mut old_ids = HashSet::new();
+        let mut new_ids = HashSet::new();
+        if !mapping
+            .iter()
+            .all(|(old, new)| old_ids.insert(*old) && new_ids.insert(*new))
+        {
+            return Err(FastCryptoError::InvalidInput); // expected unique ids
+        }
+        Ok(mapping.iter().map(|(_, new)| self.eval(*new)).collect())
+    }
+
+    /// Given at least `t` evaluations of a polynomial of degree t-1 for parties with indices in
+    /// 1, ..., n, of which some may be incorrect, it will recover the polynomial's constant term.
+    ///
+    /// The polynomial is first interpolated from the `t` evaluations selected by
+    /// [Poly::select_subset] and checked against the remaining ones. Only if this fails, up to
+    /// e = (m - t) / 2 incorrect evaluations out of m are corrected using the Berlekamp-Welch
+    /// algorithm. Returns [FastCryptoError::NotEnoughInputs] if there are less than `t`
+    /// evaluations or too many incorrect ones, and [FastCryptoError::InvalidInput] if an index is
+    /// duplicated or larger than `n`.
+    pub fn recover_c0_robust(
+        t: u32,
+        n: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>>,
+    ) -> FastCryptoResult<C> {
+        let shares = shares.map(|s| s.borrow().clone()).collect::<Vec<_>>();
+        if shares.iter().map(|s| s.index).collect::<HashSet<_>>().len() != shares.len()
+            || shares.iter().any(|s| s.index.get() > n)
+        {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        if t == 0 || shares.len() < t as usize {
+            return Err(FastCryptoError::NotEnoughInputs);
+        }
+
+        // Fast path: interpolate from t evaluations and check the others.
+        let subset = Self::select_subset(t, shares.iter())?;
+        let others = shares
+            .iter()
+            .filter(|s| !subset.iter().any(|u| u.index == s.index))
+            .collect::<Vec<_>>();
+        let domain = others
+            .iter()
+            .map(|s| C::from(s.index.get().into()))
+            .collect::<Vec<_>>();
+        let expected = Self::recover_all_evals(t, subset.iter(), &domain)?;
+        if others.iter().zip(&expected).all(|(s, e)| s.value == *e) {
+            return Self::recover_c0(t, subset.iter());
+        }
+
+        let max_errors = (shares.len() - t as usize) / 2;
+        if max_errors == 0 {
+            return Err(FastCryptoError::NotEnoughInputs);
+        }
+        let p = Self::berlekamp_welch(t as usize, max_errors, &shares)
+            .ok_or(FastCryptoError::NotEnoughInputs)?;
+        let agreeing = shares.iter().filter(|s| p.eval(s.index) == **s).count();
+        if agreeing < shares.len() - max_errors {
+            return Err(FastCryptoError::NotEnoughInputs);
+        }
+        Ok(*p.c0())
+    }
+
+    /// Berlekamp-Welch decoding of a polynomial p of degree t-1 from the given evaluations
+    /// (x_i, y_i), of which at most e are incorrect: Finds a monic E of degree e and Q of degree
+    /// t-1+e with Q(x_i) = y_i E(x_i) for all i, and returns Q / E. Requires m >= t + 2e
+    /// evaluations. Returns None if no such polynomials exist or if E does not divide Q.
+    fn berlekamp_welch(t: usize, e: usize, shares: &[Eval<C>]) -> Option<Self> {
+        debug_assert!(shares.len() >= t + 2 * e);
+        // The unknowns are (q_0, ..., q_{t+e-1}, e_0, ..., e_{e-1}) and each evaluation gives the
+        // equation \sum_k q_k x_i^k - y_i \sum_{j<e} e_j x_i^j = y_i x_i^e.
+        let (rows, rhs): (Vec<_>, Vec<_>) = shares
+            .iter()
+            .map(|s| {
+                let x = C::from(s.index.get().into());
+                let powers = std::iter::successors(Some(C::generator()), |p| Some(*p * x))
+                    .take(t + e + 1)
+                    .collect::<Vec<_>>();
+                let row = powers[..t + e]
+                    .iter()
+                    .copied()
+                    .chain(powers[..e].iter().map(|p| -(*p * s.value)))
+                    .collect::<Vec<_>>();
+                (row, powers[e] * s.value)
+            })
+            .unzip();
+        let solution = Self::solve_linear_system(rows, rhs)?;
+
+        let q = &solution[..t + e];
+        let mut error_locator = solution[t + e..].to_vec();
+        error_locator.push(C::generator());
+        Self::divide_exactly(q, &error_locator)
+    }
+
+    /// Returns a solution of the linear system Ax = b using Gaussian elimination, where free
+    /// variables are set to zero, or None if the system has no solution.
+    fn solve_linear_system(mut a: Vec<Vec<C>>, mut b: Vec<C>) -> Option<Vec<C>> {
+        let columns = a.first().map_or(0, |row| row.len());
+        let mut pivots = Vec::new();
+        let mut row = 0;
+        for column in 0..columns {
+            let Some(pivot) = (row..a.len()).find(|r| a[*r][column] != C::zero()) else {
+                continue;
+            };
+            a.swap(row, pivot);
+            b.swap(row, pivot);
+            let inv = a[row][column].inverse().expect("nonzero");
+            a[row].iter_mut().for_each(|v| *v = *v * inv);
+            b[row] = b[row] * inv;
+            for r in 0..a.len() {
+                let factor = a[r][column];
+                if r == row || factor == C::zero() {
+                    continue;
+                }
+                for c in column..columns {
+                    let v = a[row][c] * factor;
+                    a[r][c] -= v;
+                }
+                let v = b[row] * factor;
+                b[r] -= v;
+            }
+            pivots.push(column);
+            row += 1;
+        }
+        // The remaining equations are 0 = b_r.
+        if b[row..].iter().any(|v| *v != C::zero()) {
+            return None;
+        }
+        let mut solution = vec![C::zero(); columns];
+        for (r, column) in pivots.into_iter().enumerate() {
+            solution[column] = b[r];
+        }
+        Some(solution)
+    }
+
+    /// Returns the polynomial numerator / denominator for a monic denominator, or None if the
+    /// remainder is not zero.
+    fn divide_exactly(numerator: &[C], denominator: &[C]) -> Option<Self> {
+        let d = denominator.len() - 1;
+        if numerator.len() <= d {
+            return None;
+        }
+        let mut remainder = numerator.to_vec();
+        let mut quotient = vec![C::zero(); numerator.len() - d];
+        for i in (0..quotient.len()).rev() {
+            let coefficient = remainder[i + d];
+            quotient[i] = coefficient;
+            for (j, c) in denominator.iter().enumerate() {
+                remainder[i + j] -= *c * coefficient;
+            }
+        }
+        if remainder.iter().any(|c| *c != C::zero()) {
+            return None;
+        }
+        Some(Self::from(quotient))
+    }
+}
+
+/// Number of random bytes consumed per coefficient by [Poly::rand_batched].
+const RAND_BATCHED_BYTES_PER_COEFFICIENT: usize = 64;
+
+impl<C: Scalar + FiatShamirChallenge> Poly<C> {
+    /// Same as [Poly::rand], but draws the randomness for all coefficients with a single call to
+    /// the RNG, which is faster for large degrees. Each coefficient is derived from 64 random bytes
+    /// by a wide reduction modulo the group order, thus no rejection sampling is needed and the
+    /// coefficients are statistically indistinguishable from uniform.
+    pub fn rand_batched<R: AllowedRng>(degree: u32, rng: &mut R) -> Self {
+        let mut buffer = vec![0u8; (degree as usize + 1) * RAND_BATCHED_BYTES_PER_COEFFICIENT];
+        rng.fill_bytes(&mut buffer);
+        let coeffs = buffer
+            .chunks_exact(RAND_BATCHED_BYTES_PER_COEFFICIENT)
+            .map(C::fiat_shamir_reduction_to_group_element)
+            .collect::<Vec<C>>();
+        buffer.zeroize();
+        Self::from(coeffs)
+    }
+}
+
+impl<C: Scalar + Zeroize> Poly<C> {
+    /// Given exactly `t` polynomial evaluations, it will recover the polynomial's constant term
+    /// and return its canonical serialization, e.g., to be used as input to a KDF. The recovered
+    /// scalar is zeroized before returning.
+    pub fn recover_c0_bytes(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
+    ) -> FastCryptoResult<Vec<u8>> {
+        let mut c0 = Self::recover_c0(t, shares)?;
+        let bytes = bcs::to_bytes(&c0).expect("serialize should never fail");
+        c0.zeroize();
+        Ok(bytes)
+    }
+
+    /// Same as [Poly::recover_c0], but the recovered constant term is wrapped in a [Secret] which
+    /// is zeroized when dropped.
+    pub fn recover_secret(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
+    ) -> FastCryptoResult<Secret<C>> {
+        Self::recover_c0(t, shares).map(Secret)
+    }
+
+    /// Given exactly `t` polynomial evaluations, it will recover the polynomial's constant term and
+    /// return the result of `f` applied to it, e.g., to derive a key from the secret. The recovered
+    /// scalar is zeroized before returning, thus the raw secret only lives as long as `f` needs it.
+    pub fn recover_c0_map<T>(
+        t: u32,
+        shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
+        f: impl FnOnce(C) -> T,
+    ) -> FastCryptoResult<T> {
+        let mut c0 = Self::recover_c0(t, shares)?;
+        let result = f(c0);
+        c0.zeroize();
+        Ok(result)
+    }
+}
+
+impl<C: Pairing> Poly<C> {
+    /// Maps the coefficients of the polynomial to the target group by pairing them with a fixed
+    /// element. Since e(p(i), h) = e(\sum c_j i^j, h) = \sum e(c_j, h) i^j, evaluating the
+    /// resulting polynomial at i gives e(p(i), h). Useful for threshold schemes where shares are
+    /// verified in the target group, since the pairings with h are then computed only once.
+    pub fn pair_coefficients(&self, fixed: &C::Other) -> Poly<<C as Pairing>::Output> {
+        Poly::from(self.0.iter().map(|c| c.pairing(fixed)).collect::<Vec<_>>())
+    }
+}
+
+impl<C: Pairing> Poly<C>
+where
+    C::Other: GroupElement<ScalarType = C::ScalarType>,
+    <C as Pairing>::Output: GroupElement,
+{
+    /// Verifies a KZG-style opening of the polynomial at index i, i.e., that value = g^{p(i)} for
+    /// the polynomial p whose coefficients in the exp are given by this polynomial. The opening
+    /// proof is g^{q(s)} for the quotient q(x) = (p(x) - p(i)) / (x - i), and is checked using
+    /// e(proof, h^{s - i}) = e(g^{p(s)}, h) - e(value, h), where e(g^{p(s)}, h) is computed as
+    /// \sum_j e(g^{a_j}, h^{s^j}) from the coefficients g^{a_j}.
+    ///
+    /// The structured reference string `srs` must be (h, h^s, h^{s^2}, ..., h^{s^d}) for the
+    /// generator h of the other group, a secret s that is unknown to the prover (e.g., generated
+    /// by a trusted setup), and d at least the degree of the polynomial (and at least 1). The
+    /// prover needs the corresponding powers (g^{s^j})_j to compute proofs.
+    pub fn verify_opening(
+        &self,
+        i: ShareIndex,
+        value: &C,
+        proof: &C,
+        srs: &[C::Other],
+    ) -> FastCryptoResult<()> {
+        if srs.len() < self.0.len() || srs.len() < 2 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let commitment = self
+            .0
+            .iter()
+            .zip(srs)
+            .fold(<C as Pairing>::Output::zero(), |acc, (a, h)| {
+                acc + a.pairing(h)
+            });
+        let lhs = proof.pairing(&(srs[1] - srs[0] * C::ScalarType::from(i.get().into())));
+        if lhs == commitment - value.pairing(&srs[0]) {
+            Ok(())
+        } else {
+            Err(FastCryptoError::InvalidProof)
+        }
+    }
+}
+
+impl<C: GroupElement + MultiScalarMul> Poly<C> {
+    /// Given exactly `t` polynomial evaluations, it will recover the polynomial's
     /// constant term.
     pub fn recover_c0_msm(
         t: u32,
         shares: impl Iterator<Item = impl Borrow<Eval<C>>> + Clone,
     ) -> Result<C, FastCryptoError> {
-        let coeffs = Self::get_lagrange_coefficients_for_c0(t, shares.clone())?;
+        let coeffs = Self::get_lagrange_coefficients_for_c0(t, shares.clone(), None)?;
         let plain_shares = shares.map(|s| s.borrow().value).collect::<Vec<_>>();
-        let res = C::multi_scalar_mul(&coeffs, &plain_shares).expect("sizes match");
-        Ok(res)
+        if coeffs.len() != plain_shares.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        C::multi_scalar_mul(&coeffs, &plain_shares)
+    }
+
+    /// Evaluates the polynomial at the specified value using a multi-scalar multiplication of the
+    /// coefficients with the powers of the index.
+    pub fn checked_eval_msm(&self, i: ShareIndex) -> FastCryptoResult<Eval<C>> {
+        let x = C::ScalarType::from(i.get().into());
+        let powers = std::iter::successors(Some(C::ScalarType::generator()), |p| Some(*p * x))
+            .take(self.0.len())
+            .collect::<Vec<_>>();
+        Ok(Eval {
+            index: i,
+            value: self.eval_msm_with_powers(&powers)?,
+        })
+    }
+
+    /// Returns \sum c_j x_j for the coefficients c_j of the polynomial and the given powers
+    /// x_j = x^j of the evaluation point x. Returns [FastCryptoError::InvalidInput] if the number
+    /// of powers is not equal to the number of coefficients.
+    pub fn eval_msm_with_powers(&self, powers: &[C::ScalarType]) -> FastCryptoResult<C> {
+        if powers.len() != self.0.len() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        C::multi_scalar_mul(powers, &self.0)
+    }
+
+    /// Evaluates the polynomial at the indices 1, ..., n of a committee. Only the first d+1
+    /// evaluations, for the degree d, are computed with an MSM each. Since the (d+1)-th forward
+    /// differences of a polynomial of degree d are zero, the remaining evaluations follow from the
+    /// forward differences of the first ones using d group additions per index, i.e., without any
+    /// scalar multiplications. Same result as evaluating each index with [Poly::eval]. Returns
+    /// [FastCryptoError::InvalidInput] if the polynomial has no coefficients.
+    pub fn eval_committee_msm(&self, n: u32) -> FastCryptoResult<Vec<Eval<C>>> {
+        let len = self.degree_checked()? as usize + 1;
+        let mut powers = vec![C::ScalarType::generator(); len];
+        let mut values = (1..=n.min(len as u32))
+            .map(|i| {
+                let x = C::ScalarType::from(i.into());
+                for k in 1..len {
+                    powers[k] = powers[k - 1] * x;
+                }
+                self.eval_msm_with_powers(&powers)
+            })
+            .collect::<FastCryptoResult<Vec<_>>>()?;
+
+        if n as usize > len {
+            // diffs[k] is the k-th forward difference at the current index, starting at index 1.
+            let mut diffs = values.clone();
+            for k in 1..len {
+                for j in (k..len).rev() {
+                    diffs[j] = diffs[j] - diffs[j - 1];
+                }
+            }
+            for i in 2..=n as usize {
+                for k in 0..len - 1 {
+                    diffs[k] = diffs[k] + diffs[k + 1];
+                }
+                if i > len {
+                    values.push(diffs[0]);
+                }
+            }
+        }
+
+        Ok(values
+            .into_iter()
+            .zip(1..)
+            .map(|(value, i)| Eval {
+                index: ShareIndex::new(i).expect("nonzero"),
+                value,
+            })
+            .collect())
+    }
+
+    /// Checks that generator * share_d = public_d(my_index) for all given pairs (public_d,
+    /// share_d), e.g., the shares of a party from multiple dealings, using a single random
+    /// linear combination of the checks (see [verify_pairs]).
+    pub fn verify_my_shares<R: AllowedRng>(
+        my_index: ShareIndex,
+        entries: &[(PublicPoly<C>, C::ScalarType)],
+        rng: &mut R,
+    ) -> FastCryptoResult<()> {
+        let pairs = entries
+            .iter()
+            .map(|(public_poly, share)| (*share, public_poly.eval(my_index).value))
+            .collect::<Vec<_>>();
+        verify_pairs(&pairs, rng)
+    }
+}
+
+impl<C: GroupElement + MultiScalarMul + Serialize> Poly<C>
+where
+    C::ScalarType: FiatShamirChallenge,
+{
+    /// Checks that a set of shares is consistent with the polynomial in the exp by checking that
+    /// (\sum r^i v_i)*G = \sum r^i p(i), where r is a Fiat-Shamir challenge derived from the
+    /// polynomial, the shares and the given domain separation input. Unlike
+    /// [crate::dl_verification::verify_poly_evals], the result is deterministic and can be
+    /// reproduced by anyone with the same transcript. Uses the default tag [FIAT_SHAMIR_DST], see
+    /// [Poly::verify_shares_fiat_shamir_with_tag].
+    pub fn verify_shares_fiat_shamir(
+        &self,
+        shares: &[(ShareIndex, C::ScalarType)],
+        domain_sep: &[u8],
+    ) -> FastCryptoResult<()> {
+        self.verify_shares_fiat_shamir_with_tag(
+            shares,
+            domain_sep,
+            &DomainSeparationTag::new(FIAT_SHAMIR_DST),
+        )
+    }
+
+    /// Same as [Poly::verify_shares_fiat_shamir], but the challenge is derived with the given
+    /// protocol specific tag.
+    pub fn verify_shares_fiat_shamir_with_tag(
+        &self,
+        shares: &[(ShareIndex, C::ScalarType)],
+        domain_sep: &[u8],
+        tag: &DomainSeparationTag,
+    ) -> FastCryptoResult<()> {
+        let degree = self.degree_checked()?;
+        if degree == 0 {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        if shares.is_empty() {
+            return Ok(());
+        }
+        let challenge = self.fiat_shamir_challenge(shares, domain_sep, tag);
+        let rs = std::iter::successors(Some(C::ScalarType::generator()), |r| Some(*r * challenge))
+            .take(shares.len())
+            .collect::<Vec<_>>();
+
+        let lhs = C::generator()
+            * rs.iter()
+                .zip(shares.iter())
+                .fold(C::ScalarType::zero(), |acc, (r, (_, v))| acc + *r * *v);
+
+        let indices_as_scalars = shares
+            .iter()
+            .map(|(i, _)| C::ScalarType::from(i.get().into()))
+            .collect::<Vec<_>>();
+        let coeffs = batch_coefficients(&rs, &indices_as_scalars, degree);
+        let rhs = C::multi_scalar_mul(&coeffs, &self.0)?;
+
+        if lhs != rhs {
+            Err(FastCryptoError::InvalidProof)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the challenge for Fiat-Shamir.
+    pub(crate) fn fiat_shamir_challenge(
+        &self,
+        shares: &[(ShareIndex, C::ScalarType)],
+        domain_sep: &[u8],
+        tag: &DomainSeparationTag,
+    ) -> C::ScalarType {
+        let output = RandomOracle::new("verify_shares").evaluate(&(
+            tag.as_bytes(),
+            domain_sep,
+            self,
+            shares,
+        ));
+        C::ScalarType::fiat_shamir_reduction_to_group_element(&output)
+    }
+}
+
+impl<C: GroupElement + Serialize> Poly<C>
+where
+    C::ScalarType: FiatShamirChallenge,
+{
+    /// Creates a proof that the private polynomials `private_a` and `private_b` have the same
+    /// (non-zero) constant term x, to be verified using [Poly::verify_equal_c0] with their
+    /// commitments. The proof is a NIZKPoK of x s.t. c0 = xG for both commitments, and is bound
+    /// to the two commitments via the random oracle. It reveals nothing beyond c0 itself.
+    pub fn prove_equal_c0<R: AllowedRng>(
+        private_a: &PrivatePoly<C>,
+        private_b: &PrivatePoly<C>,
+        random_oracle: &RandomOracle,
+        rng: &mut R,
+    ) -> FastCryptoResult<DLNizk<C>> {
+        let c0 = private_a.c0_checked()?;
+        if c0 != private_b.c0_checked()? || *c0 == C::ScalarType::zero() {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let public_a = private_a.commit::<C>();
+        let public_b = private_b.commit::<C>();
+        Ok(DLNizk::create(
+            c0,
+            public_a.c0(),
+            &Self::equal_c0_aux_input(&public_a, &public_b),
+            random_oracle,
+            rng,
+        ))
+    }
+
+    /// Verifies a proof created by [Poly::prove_equal_c0].
+    pub fn verify_equal_c0(
+        public_a: &Self,
+        public_b: &Self,
+        proof: &DLNizk<C>,
+        random_oracle: &RandomOracle,
+    ) -> FastCryptoResult<()> {
+        let c0 = public_a.c0_checked()?;
+        if c0 != public_b.c0_checked()? {
+            return Err(FastCryptoError::InvalidProof);
+        }
+        proof.verify(
+            c0,
+            &Self::equal_c0_aux_input(public_a, public_b),
+            random_oracle,
+        )
+    }
+
+    fn equal_c0_aux_input(public_a: &Self, public_b: &Self) -> Vec<u8> {
+        bcs::to_bytes(&(public_a, public_b)).expect("serialize should never fail")
     }
 }
@@ -0,0 +1,136 @@
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Utilities for testing threshold protocols built on this crate, e.g., in integration tests of
+//! downstream crates, available with the `test-utils` feature.
+
+use crate::polynomial::{Eval, Poly, PrivatePoly, PublicPoly};
+use crate::types::ShareIndex;
+use fastcrypto::error::{FastCryptoError, FastCryptoResult};
+use fastcrypto::groups::GroupElement;
+use fastcrypto::traits::AllowedRng;
+use itertools::{Either, Itertools};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+/// The maximal number of subsets returned by [all_authorized_subsets]. If there are more subsets
+/// of size t, this many are sampled at random instead.
+pub const MAX_AUTHORIZED_SUBSETS: usize = 1000;
+
+/// The result of a DKG among n parties with threshold t in which every party is an honest dealer.
+#[derive(Debug, Clone)]
+pub struct DkgFixture<G: GroupElement> {
+    pub t: u32,
+    pub n: u32,
+    /// The private polynomial of each dealer.
+    pub private_polys: Vec<PrivatePoly<G>>,
+    /// The public polynomial of each dealer.
+    pub public_polys: Vec<PublicPoly<G>>,
+    /// `shares[d][i]` is the share of the party with index i + 1 from dealer d.
+    pub shares: Vec<Vec<Eval<G::ScalarType>>>,
+    /// The sum of the public polynomials of all dealers.
+    pub aggregate_public_poly: PublicPoly<G>,
+    /// The constant term of the aggregate public polynomial.
+    pub aggregate_public_key: G,
+    /// The final share of each party, i.e., the sum of the shares it received.
+    pub aggregate_shares: Vec<Eval<G::ScalarType>>,
+}
+
+/// Generates a [DkgFixture] for the parties with indices 1, ..., n. Panics if t is zero or larger
+/// than n.
+pub fn generate_dkg_fixture<G: GroupElement, R: AllowedRng>(
+    t: u32,
+    n: u32,
+    rng: &mut R,
+) -> DkgFixture<G> {
+    assert!(t > 0 && t <= n, "threshold must be in 1..=n");
+    let private_polys = (0..n)
+        .map(|_| PrivatePoly::<G>::rand(t - 1, rng))
+        .collect::<Vec<_>>();
+    let (public_polys, shares): (Vec<_>, Vec<_>) = private_polys
+        .iter()
+        .map(|p| p.deal_private_and_public::<G>(n).expect("n >= t"))
+        .unzip();
+
+    let mut aggregate_public_poly = PublicPoly::<G>::zero();
+    public_polys
+        .iter()
+        .for_each(|p| aggregate_public_poly.add(p));
+    let aggregate_public_key = Poly::aggregate_key(&public_polys).expect("t > 0");
+    let aggregate_shares = (1..=n)
+        .map(|i| {
+            let received = shares
+                .iter()
+                .map(|s: &Vec<Eval<G::ScalarType>>| s[i as usize - 1].value)
+                .collect::<Vec<_>>();
+            Eval {
+                index: ShareIndex::new(i).expect("nonzero"),
+                value: Poly::<G>::aggregate_shares(&received),
+            }
+        })
+        .collect();
+
+    DkgFixture {
+        t,
+        n,
+        private_polys,
+        public_polys,
+        shares,
+        aggregate_public_poly,
+        aggregate_public_key,
+        aggregate_shares,
+    }
+}
+
+/// Returns all subsets of size t of the indices 1, ..., n, each sorted, if there are at most
+/// [MAX_AUTHORIZED_SUBSETS] of them, and otherwise [MAX_AUTHORIZED_SUBSETS] random such subsets.
+/// Panics if t is zero or larger than n.
+pub fn all_authorized_subsets(t: u32, n: u32) -> impl Iterator<Item = Vec<ShareIndex>> {
+    assert!(t > 0 && t <= n, "threshold must be in 1..=n");
+    let indices = (1..=n)
+        .map(|i| ShareIndex::new(i).expect("nonzero"))
+        .collect_vec();
+    if binomial_at_most(n, t, MAX_AUTHORIZED_SUBSETS) {
+        Either::Left(indices.into_iter().combinations(t as usize))
+    } else {
+        let mut rng = thread_rng();
+        Either::Right((0..MAX_AUTHORIZED_SUBSETS).map(move |_| {
+            let mut subset = indices.clone();
+            subset.shuffle(&mut rng);
+            subset.truncate(t as usize);
+            subset.sort();
+            subset
+        }))
+    }
+}
+
+/// Checks that the constant term of the polynomial is recovered from the evaluations on each of
+/// the subsets returned by [all_authorized_subsets]. Returns [FastCryptoError::InvalidProof] if
+/// one of the subsets recovers a different value.
+pub fn verify_all_subsets_reconstruct<C: GroupElement>(
+    poly: &Poly<C>,
+    t: u32,
+    n: u32,
+) -> FastCryptoResult<()> {
+    for subset in all_authorized_subsets(t, n) {
+        let shares = subset.iter().map(|i| poly.eval(*i));
+        if Poly::<C>::recover_c0(t, shares)? != *poly.c0() {
+            return Err(FastCryptoError::InvalidProof);
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if the binomial coefficient (n choose t) is at most `max`.
+fn binomial_at_most(n: u32, t: u32, max: usize) -> bool {
+    // (n choose i) is increasing for i <= n/2, so we can stop as soon as it exceeds max.
+    let k = t.min(n - t) as u128;
+    let mut binomial = 1u128;
+    for i in 0..k {
+        binomial = binomial * (n as u128 - i) / (i + 1);
+        if binomial > max as u128 {
+            return false;
+        }
+    }
+    true
+}
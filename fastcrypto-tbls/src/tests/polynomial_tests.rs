@@ -6,18 +6,30 @@
 //
 
 use crate::polynomial::*;
-use crate::types::ShareIndex;
-use fastcrypto::groups::bls12381::{G1Element, G2Element, Scalar as BlsScalar};
+use crate::random_oracle::RandomOracle;
+use crate::types::{ShareIndex, WithContext};
+use fastcrypto::error::FastCryptoError;
+use fastcrypto::groups::bls12381::{
+    G1Element, G2Element, Scalar as BlsScalar, G1_ELEMENT_BYTE_LENGTH, G2_ELEMENT_BYTE_LENGTH,
+};
 use fastcrypto::groups::ristretto255::{RistrettoPoint, RistrettoScalar};
-use fastcrypto::groups::{GroupElement, MultiScalarMul, Scalar};
+use fastcrypto::groups::{
+    FiatShamirChallenge, FromTrustedByteArray, GroupElement, MultiScalarMul, Pairing, Scalar,
+};
+use fastcrypto::serde_helpers::ToFromByteArray;
 use rand::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::num::NonZeroU32;
+use zeroize::Zeroize;
 
 const I10: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(10) };
 
 #[generic_tests::define]
 mod scalar_tests {
     use super::*;
+    use itertools::{Either, Itertools};
 
     #[test]
     fn test_degree<S: Scalar>() {
@@ -26,6 +38,16 @@ mod scalar_tests {
         assert_eq!(p.degree(), s);
     }
 
+    #[test]
+    fn test_rand_with_len<S: Scalar>() {
+        for len in [1, 2, 5, 100] {
+            let p = Poly::<S>::rand_with_len(len, &mut thread_rng()).unwrap();
+            assert_eq!(p.as_vec().len(), len);
+            assert_eq!(p.degree() as usize, len - 1);
+        }
+        assert!(Poly::<S>::rand_with_len(0, &mut thread_rng()).is_err());
+    }
+
     #[test]
     fn add<S: Scalar>() {
         let p1 = Poly::<S>::rand(3, &mut thread_rng());
@@ -34,88 +56,2036 @@ mod scalar_tests {
         res.add(&p2);
         assert_eq!(res, p1);
 
-        let p1 = Poly::<S>::zero();
-        let p2 = Poly::<S>::rand(3, &mut thread_rng());
-        let mut res = p1;
-        res.add(&p2);
-        assert_eq!(res, p2);
+        let p1 = Poly::<S>::zero();
+        let p2 = Poly::<S>::rand(3, &mut thread_rng());
+        let mut res = p1;
+        res.add(&p2);
+        assert_eq!(res, p2);
+
+        let p1 = Poly::<S>::rand(3, &mut thread_rng());
+        let p2 = Poly::<S>::rand(5, &mut thread_rng());
+        let mut p3 = p1.clone();
+        p3.add(&p2);
+        assert_eq!(p1.eval(I10).value + p2.eval(I10).value, p3.eval(I10).value);
+    }
+
+    #[test]
+    fn test_recover_c0_errors<S: Scalar>() {
+        let degree = 4;
+        let threshold = degree + 1;
+        let poly = Poly::<S>::rand(4, &mut thread_rng());
+        // insufficient shares gathered
+        let shares = (1..threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        Poly::<S>::recover_c0(threshold, shares).unwrap_err();
+        // duplications
+        let shares = (1..=threshold)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .chain(std::iter::once(poly.eval(ShareIndex::new(1).unwrap()))); // duplicate value 1
+        Poly::<S>::recover_c0(threshold, shares).unwrap_err();
+    }
+
+    #[test]
+    fn test_recover_c0<S: Scalar>() {
+        let poly = Poly::<S>::rand(123, &mut thread_rng());
+        // insufficient shares gathered
+        let mut shares = (1..300)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+        let c0 = poly.c0();
+        for _ in 0..10 {
+            shares.shuffle(&mut thread_rng());
+            let used_shares = shares.iter().take(124);
+            assert_eq!(c0, &Poly::<S>::recover_c0(124, used_shares).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_index<S: Scalar>() {
+        let mut p = Poly::<S>::rand(3, &mut thread_rng());
+        assert_eq!(&p[0], p.c0());
+        assert_eq!(p[3], p.as_vec()[3]);
+        let c2 = p[2];
+        p[1] = S::zero();
+        p[2] += S::generator();
+        assert_eq!(p.as_vec()[1], S::zero());
+        assert_eq!(p.as_vec()[2], c2 + S::generator());
+    }
+
+    #[test]
+    fn test_clone_into<S: Scalar>() {
+        let p = Poly::<S>::rand(5, &mut thread_rng());
+        let mut dest = Poly::<S>::rand(9, &mut thread_rng());
+        let ptr = dest.as_vec().as_ptr();
+        p.clone_into(&mut dest);
+        assert_eq!(dest, p);
+        // The allocation is reused since it is large enough.
+        assert_eq!(dest.as_vec().as_ptr(), ptr);
+        assert!(dest.as_vec().capacity() >= 10);
+
+        // A smaller destination grows.
+        let mut dest = Poly::<S>::from(vec![S::zero()]);
+        p.clone_into(&mut dest);
+        assert_eq!(dest, p);
+        assert_eq!(dest.eval(I10), p.eval(I10));
+    }
+
+    #[test]
+    fn test_checked_accessors<S: Scalar>() {
+        let p = Poly::<S>::rand(3, &mut thread_rng());
+        assert_eq!(p.degree_checked(), Ok(3));
+        assert_eq!(p.c0_checked(), Ok(p.c0()));
+        assert_eq!(p.coefficient_checked(3), Ok(&p[3]));
+        assert_eq!(p.coefficient_checked(4), Err(FastCryptoError::InvalidInput));
+
+        // An empty polynomial, e.g., from untrusted input, on which degree() and c0() panic.
+        let empty = bcs::from_bytes::<Poly<S>>(&[0]).unwrap();
+        assert_eq!(empty, Poly::from(Vec::new()));
+        assert_eq!(empty.degree_checked(), Err(FastCryptoError::InvalidInput));
+        assert_eq!(empty.c0_checked(), Err(FastCryptoError::InvalidInput));
+        assert_eq!(
+            empty.coefficient_checked(0),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            empty.clone().try_into_constant(),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(empty.eval_estrin(I10), empty.eval(I10));
+        let mut sum = empty.clone();
+        assert!(sum.checked_add_same_type(&empty, |_| ()).is_ok());
+        assert_eq!(sum, empty);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_degree_of_empty_poly<S: Scalar>() {
+        let _ = Poly::<S>::from(Vec::new()).degree();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds<S: Scalar>() {
+        let p = Poly::<S>::rand(3, &mut thread_rng());
+        let _ = p[4];
+    }
+
+    #[test]
+    fn test_fold_eval<S: Scalar>() {
+        let p = Poly::<S>::rand(5, &mut thread_rng());
+        let x = S::rand(&mut thread_rng());
+        assert_eq!(
+            p.fold_eval(&x, S::zero(), |_, acc, _| *acc),
+            p.eval_at_scalar(&x)
+        );
+
+        // The intermediate accumulators are the evaluations of the "upper" parts of p.
+        let steps = p.fold_eval(&x, Vec::new(), |mut steps, acc, coeff| {
+            steps.push((*acc, *coeff));
+            steps
+        });
+        assert_eq!(steps.len(), 6);
+        assert_eq!(steps[0], (p[5], p[5]));
+        assert_eq!(steps[1], (p[5] * x + p[4], p[4]));
+        assert_eq!(steps[5].0, p.eval_at_scalar(&x));
+    }
+
+    #[test]
+    fn test_eval_at_neg_index<S: Scalar>() {
+        let p = Poly::<S>::rand(5, &mut thread_rng());
+        let x = S::from(I10.get().into());
+        assert_eq!(p.eval_at_scalar(&x), p.eval(I10).value);
+        assert_eq!(p.eval_at_neg_index(I10), p.eval_at_scalar(&-x));
+
+        // p(x) = 1 + 2x, thus p(-10) = -19
+        let p = Poly::<S>::from(vec![S::from(1), S::from(2)]);
+        assert_eq!(p.eval_at_neg_index(I10), -S::from(19));
+    }
+
+    #[test]
+    fn test_eval_cache<S: Scalar>() {
+        let mut p = Poly::<S>::rand(5, &mut thread_rng());
+        let uncached = p.eval_at_scalar(&S::from(I10.get().into()));
+        assert_eq!(p.eval(I10).value, uncached);
+        assert_eq!(p.eval(I10).value, uncached);
+        // Secret shares are never memoized.
+        #[cfg(feature = "cached")]
+        assert_eq!(p.cached_evals(), 0);
+
+        // Clones and deserialized polynomials agree, and only the coefficients are serialized.
+        assert_eq!(p.clone().eval(I10).value, uncached);
+        let bytes = bcs::to_bytes(&p).unwrap();
+        assert_eq!(bytes, bcs::to_bytes(p.as_vec()).unwrap());
+        let deserialized: Poly<S> = bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, p);
+        assert_eq!(deserialized.eval(I10).value, uncached);
+
+        // Modifying the coefficients invalidates previous evaluations.
+        p[1] += S::generator();
+        assert_eq!(p.eval(I10).value, uncached + S::from(I10.get().into()));
+        p.coefficients_mut()[0] += S::generator();
+        p.add(&Poly::from(vec![S::generator()]));
+        assert_eq!(p.eval(I10).value, uncached + S::from(I10.get() as u128 + 2));
+        assert_eq!(
+            p.eval(I10).value,
+            p.eval_at_scalar(&S::from(I10.get().into()))
+        );
+    }
+
+    #[test]
+    fn test_sum_of_coefficients<S: Scalar>() {
+        let p = Poly::<S>::rand(5, &mut thread_rng());
+        assert_eq!(
+            p.sum_of_coefficients(),
+            p.eval(ShareIndex::new(1).unwrap()).value
+        );
+        assert_eq!(
+            p.sum_of_coefficients(),
+            p.as_vec().iter().fold(S::zero(), |acc, c| acc + c)
+        );
+        assert_eq!(Poly::<S>::zero().sum_of_coefficients(), S::zero());
+    }
+
+    #[test]
+    fn test_high_coefficients<S: Scalar>() {
+        let p = Poly::<S>::rand(4, &mut thread_rng());
+        assert_eq!(p.high_coefficients(2).unwrap(), &p.as_vec()[3..]);
+        assert_eq!(p.high_coefficients(5).unwrap(), p.as_vec().as_slice());
+        assert!(p.high_coefficients(0).unwrap().is_empty());
+        assert_eq!(p.high_coefficients(6), Err(FastCryptoError::InvalidInput));
+    }
+
+    #[test]
+    fn test_split_even_odd<S: Scalar>() {
+        let x = S::rand(&mut thread_rng());
+        for degree in [0, 1, 4, 7] {
+            let p = Poly::<S>::rand(degree, &mut thread_rng());
+            let (even, odd) = p.split_even_odd();
+            assert_eq!(even.degree(), degree / 2);
+            assert_eq!(
+                even.eval_at_scalar(&(x * x)) + x * odd.eval_at_scalar(&(x * x)),
+                p.eval_at_scalar(&x)
+            );
+        }
+        let p = Poly::<S>::from((1..=5).map(S::from).collect::<Vec<_>>());
+        let (even, odd) = p.split_even_odd();
+        assert_eq!(even, Poly::from(vec![S::from(1), S::from(3), S::from(5)]));
+        assert_eq!(odd, Poly::from(vec![S::from(2), S::from(4)]));
+    }
+
+    #[test]
+    fn test_coefficients_mut<S: Scalar>() {
+        let mut p = Poly::<S>::rand(4, &mut thread_rng());
+        let q = p.clone();
+        let two = S::from(2);
+        p.coefficients_mut().iter_mut().for_each(|c| *c = *c * two);
+        assert_eq!(p.degree(), 4);
+        assert_eq!(p.eval(I10).value, q.eval(I10).value * two);
+    }
+
+    #[test]
+    fn test_rand_zero_c0<S: Scalar>() {
+        let threshold = 5;
+        let mask = Poly::<S>::rand_zero_c0(threshold - 1, &mut thread_rng());
+        assert_eq!(mask.degree(), threshold - 1);
+        assert_eq!(mask.c0(), &S::zero());
+
+        let mut poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let secret = *poly.c0();
+        poly.add(&mask);
+        let shares = (1..=threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        assert_eq!(Poly::<S>::recover_c0(threshold, shares).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_rand_sparse<S: Scalar>() {
+        let degree = 9;
+        for nonzero_count in [1, 4, 10] {
+            let p = Poly::<S>::rand_sparse(degree, nonzero_count, &mut thread_rng()).unwrap();
+            assert_eq!(p.as_vec().len(), degree as usize + 1);
+            assert_ne!(p.c0(), &S::zero());
+            assert_eq!(
+                p.as_vec().iter().filter(|c| **c != S::zero()).count(),
+                nonzero_count as usize
+            );
+            let shares = (1..=degree + 1).map(|i| p.eval(ShareIndex::new(i).unwrap()));
+            assert_eq!(&Poly::<S>::recover_c0(degree + 1, shares).unwrap(), p.c0());
+        }
+        assert!(Poly::<S>::rand_sparse(degree, 0, &mut thread_rng()).is_err());
+        assert!(Poly::<S>::rand_sparse(degree, 11, &mut thread_rng()).is_err());
+    }
+
+    #[test]
+    fn test_rand_batched<S: Scalar + FiatShamirChallenge>() {
+        let degree = 9;
+        let p = Poly::<S>::rand_batched(degree, &mut thread_rng());
+        assert_eq!(p.degree(), degree);
+        assert_eq!(p.as_vec().len(), degree as usize + 1);
+        assert!(p.as_vec().iter().all(|c| *c != S::zero()));
+        let shares = (1..=degree + 1).map(|i| p.eval(ShareIndex::new(i).unwrap()));
+        assert_eq!(&Poly::<S>::recover_c0(degree + 1, shares).unwrap(), p.c0());
+
+        // The fraction of set bits in the serialized coefficients should match the one of rand.
+        let ones_fraction = |p: Poly<S>| {
+            let bytes = p
+                .as_vec()
+                .iter()
+                .flat_map(|c| bcs::to_bytes(c).unwrap())
+                .collect::<Vec<_>>();
+            let ones: u32 = bytes.iter().map(|b| b.count_ones()).sum();
+            ones as f64 / (bytes.len() * 8) as f64
+        };
+        let batched = ones_fraction(Poly::<S>::rand_batched(1023, &mut thread_rng()));
+        let reference = ones_fraction(Poly::<S>::rand(1023, &mut thread_rng()));
+        assert!((batched - reference).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_multiply_shares_local<S: Scalar>() {
+        let t = 4;
+        let a = Poly::<S>::rand(t - 1, &mut thread_rng());
+        let b = Poly::<S>::rand(t - 1, &mut thread_rng());
+        let products = (1..2 * t)
+            .map(|i| {
+                let i = ShareIndex::new(i).unwrap();
+                Poly::<S>::multiply_shares_local(&a.eval(i), &b.eval(i)).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Poly::<S>::recover_c0(2 * t - 1, products.iter()).unwrap(),
+            *a.c0() * *b.c0()
+        );
+        // t shares of the product are not enough.
+        assert_ne!(
+            Poly::<S>::recover_c0(t, products.iter().take(t as usize)).unwrap(),
+            *a.c0() * *b.c0()
+        );
+        assert_eq!(
+            Poly::<S>::multiply_shares_local(&a.eval(I10), &b.eval(ShareIndex::new(1).unwrap())),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_verify_share_sum<S: Scalar>() {
+        // p(x) = 1 + 2x, thus p(1) + p(2) + p(3) = 3 + 5 + 7 = 15.
+        let p = Poly::<S>::from(vec![S::from(1), S::from(2)]);
+        let indices = (1..=3).map(|i| ShareIndex::new(i).unwrap()).collect_vec();
+        assert!(p.verify_share_sum(&indices, &S::from(15)).is_ok());
+        assert_eq!(
+            p.verify_share_sum(&indices, &S::from(16)),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert!(p.verify_share_sum(&indices[..2], &S::from(8)).is_ok());
+        assert!(p.verify_share_sum(&[], &S::zero()).is_ok());
+        assert!(p
+            .verify_share_sum(&[indices[0], indices[0]], &S::from(6))
+            .is_err());
+    }
+
+    #[test]
+    fn test_additive_to_shamir<S: Scalar>() {
+        let additive = (0..5).map(|_| S::rand(&mut thread_rng())).collect_vec();
+        let sum = additive.iter().fold(S::zero(), |acc, s| acc + s);
+        let (p, shares) = Poly::<S>::additive_to_shamir(&additive, &mut thread_rng()).unwrap();
+        assert_eq!(p.degree(), 4);
+        assert_eq!(p.c0(), &sum);
+        assert_eq!(shares.len(), 5);
+        assert_eq!(Poly::<S>::recover_c0(5, shares.iter()).unwrap(), sum);
+        assert_ne!(
+            Poly::<S>::recover_c0(4, shares.iter().take(4)).unwrap(),
+            sum
+        );
+
+        let (p, shares) = Poly::<S>::additive_to_shamir(&additive[..1], &mut thread_rng()).unwrap();
+        assert_eq!(p.degree(), 0);
+        assert_eq!(shares[0].value, additive[0]);
+        assert!(Poly::<S>::additive_to_shamir(&[], &mut thread_rng()).is_err());
+    }
+
+    #[test]
+    fn test_rand_with_secret_and_fixed_share<S: Scalar>() {
+        let secret = S::rand(&mut thread_rng());
+        let target = S::rand(&mut thread_rng());
+        let index = ShareIndex::new(7).unwrap();
+        for degree in [1, 2, 5] {
+            let poly = Poly::<S>::rand_with_secret_and_fixed_share(
+                degree,
+                secret,
+                (index, target),
+                &mut thread_rng(),
+            )
+            .unwrap();
+            assert_eq!(poly.degree(), degree);
+            assert_eq!(poly.c0(), &secret);
+            assert_eq!(poly.eval(index).value, target);
+        }
+        assert!(Poly::<S>::rand_with_secret_and_fixed_share(
+            0,
+            secret,
+            (index, target),
+            &mut thread_rng()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_try_into_constant<S: Scalar>() {
+        let c = S::rand(&mut thread_rng());
+        assert_eq!(Poly::from(vec![c]).try_into_constant(), Ok(c));
+        // leading zeros are ignored
+        assert_eq!(
+            Poly::from(vec![c, S::zero(), S::zero()]).effective_degree(),
+            0
+        );
+        assert_eq!(
+            Poly::from(vec![c, S::zero(), S::zero()]).try_into_constant(),
+            Ok(c)
+        );
+        assert_eq!(Poly::<S>::zero().try_into_constant(), Ok(S::zero()));
+
+        let p = Poly::<S>::rand(3, &mut thread_rng());
+        assert_eq!(p.effective_degree(), 3);
+        assert_eq!(p.try_into_constant(), Err(FastCryptoError::InvalidInput));
+        let p = Poly::from(vec![c, S::generator(), S::zero()]);
+        assert_eq!(p.effective_degree(), 1);
+        assert!(p.try_into_constant().is_err());
+    }
+
+    #[test]
+    fn test_batch_inverse<S: Scalar>() {
+        let values = (0..10).map(|_| S::rand(&mut thread_rng())).collect_vec();
+        let inverses = batch_inverse(&values).unwrap();
+        assert_eq!(
+            inverses,
+            values.iter().map(|v| v.inverse().unwrap()).collect_vec()
+        );
+        assert_eq!(batch_inverse(&values[..1]).unwrap(), inverses[..1]);
+        assert!(batch_inverse::<S>(&[]).unwrap().is_empty());
+
+        let mut with_zero = values;
+        with_zero[4] = S::zero();
+        assert_eq!(
+            batch_inverse(&with_zero),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_recover_c0_matches_naive_interpolation<S: Scalar>() {
+        let t = 7;
+        let p = Poly::<S>::rand(t - 1, &mut thread_rng());
+        let indices = [3u32, 5, 8, 1000, 77_777, 1 << 30, u32::MAX];
+        let shares = indices
+            .iter()
+            .map(|i| p.eval(ShareIndex::new(*i).unwrap()))
+            .collect_vec();
+
+        // \sum_j p(x_j) \prod_{m != j} x_m / (x_m - x_j), with one inversion per coefficient.
+        let x = |i: &u32| S::from(*i as u128);
+        let naive = indices.iter().zip(&shares).fold(S::zero(), |acc, (j, s)| {
+            let lambda = indices
+                .iter()
+                .filter(|m| *m != j)
+                .fold(S::generator(), |l, m| l * (x(m) / (x(m) - x(j))).unwrap());
+            acc + s.value * lambda
+        });
+        assert_eq!(naive, *p.c0());
+        assert_eq!(Poly::<S>::recover_c0(t, shares.iter()).unwrap(), naive);
+    }
+
+    #[test]
+    fn test_lagrange_coefficients_sum<S: Scalar>() {
+        for indices in [vec![1], vec![1, 2, 3], vec![4, 9, 10, 77, 1000]] {
+            let indices = indices
+                .into_iter()
+                .map(|i| ShareIndex::new(i).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                Poly::<S>::lagrange_coefficients_sum(&indices).unwrap(),
+                S::generator()
+            );
+        }
+        assert!(Poly::<S>::lagrange_coefficients_sum(&[]).is_err());
+        assert!(Poly::<S>::lagrange_coefficients_sum(&[I10, I10]).is_err());
+    }
+
+    #[test]
+    fn test_same_index_set<S: Scalar>() {
+        let indices = |v: &[u32]| {
+            v.iter()
+                .map(|i| ShareIndex::new(*i).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert!(Poly::<S>::same_index_set(
+            &indices(&[1, 2, 5]),
+            &indices(&[5, 1, 2])
+        ));
+        assert!(Poly::<S>::same_index_set(
+            &indices(&[1, 2, 5, 2]),
+            &indices(&[5, 1, 2])
+        ));
+        assert!(Poly::<S>::same_index_set(&[], &[]));
+        assert!(!Poly::<S>::same_index_set(
+            &indices(&[1, 2, 5]),
+            &indices(&[1, 2, 6])
+        ));
+        assert!(!Poly::<S>::same_index_set(
+            &indices(&[1, 2, 5]),
+            &indices(&[1, 2])
+        ));
+    }
+
+    #[test]
+    fn test_recover_c0_with_slack<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        // exactly t shares
+        let shares = (1..=threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        let (c0, slack) = Poly::<S>::recover_c0_with_slack(threshold, shares).unwrap();
+        assert_eq!(&c0, poly.c0());
+        assert_eq!(slack, 0);
+        // surplus shares, where duplicates are not counted
+        let shares = (1..=threshold + 3)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .chain(std::iter::once(poly.eval(ShareIndex::new(1).unwrap())));
+        let (c0, slack) = Poly::<S>::recover_c0_with_slack(threshold, shares).unwrap();
+        assert_eq!(&c0, poly.c0());
+        assert_eq!(slack, 3);
+        // insufficient shares gathered
+        let shares = (1..threshold)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .chain(std::iter::once(poly.eval(ShareIndex::new(1).unwrap())));
+        assert!(Poly::<S>::recover_c0_with_slack(threshold, shares).is_err());
+    }
+
+    #[test]
+    fn test_select_subset_min_spread<S: Scalar>() {
+        // Number of multiplications in the computation of the Lagrange coefficients for the given
+        // shares that do not fit in a u128, see [Poly::fast_mult].
+        let fallbacks = |subset: &[Eval<S>]| {
+            subset
+                .iter()
+                .map(|i| {
+                    subset
+                        .iter()
+                        .filter(|j| j.index != i.index)
+                        .fold((0, 1u128), |(count, remaining), j| {
+                            let diff = i.index.get().abs_diff(j.index.get()) as u128;
+                            match Poly::<S>::fast_mult(remaining, diff) {
+                                Either::Left((_, diff)) => (count + 1, diff),
+                                Either::Right(product) => (count, product),
+                            }
+                        })
+                        .0
+                })
+                .sum::<usize>()
+        };
+
+        // The smallest indices are spread out such that the products of their differences do not
+        // fit in a u128, while the indices 2^31, ..., 2^31 + 5 are consecutive.
+        let threshold = 6;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let spread = [1, 1 << 27, 2 << 27, 3 << 27, 4 << 27, 5 << 27];
+        let consecutive = (0..threshold).map(|i| (1 << 31) + i);
+        let mut shares = spread
+            .into_iter()
+            .chain(consecutive.clone())
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+        shares.shuffle(&mut thread_rng());
+
+        let smallest = Poly::<S>::select_subset(threshold, shares.iter()).unwrap();
+        assert_eq!(
+            smallest.iter().map(|s| s.index.get()).collect::<Vec<_>>(),
+            spread.to_vec()
+        );
+        assert!(fallbacks(&smallest) > 0);
+
+        let subset = Poly::<S>::select_subset_min_spread(threshold, shares.iter()).unwrap();
+        assert_eq!(
+            subset.iter().map(|s| s.index.get()).collect::<Vec<_>>(),
+            consecutive.collect::<Vec<_>>()
+        );
+        assert_eq!(fallbacks(&subset), 0);
+
+        for selection in [
+            SubsetSelection::SmallestIndices,
+            SubsetSelection::MinimalSpread,
+        ] {
+            let (c0, slack) =
+                Poly::<S>::recover_c0_with_slack_and_selection(threshold, shares.iter(), selection)
+                    .unwrap();
+            assert_eq!(&c0, poly.c0());
+            assert_eq!(slack, 6);
+        }
+        assert_eq!(
+            Poly::<S>::select_subset_min_spread(threshold, shares.iter().take(3)),
+            Err(FastCryptoError::NotEnoughInputs)
+        );
+    }
+
+    #[test]
+    fn test_recover_c0_with_context<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        let err = Poly::<S>::recover_c0(threshold, shares.clone().skip(1))
+            .with_context("reconstruction")
+            .unwrap_err();
+        assert!(err.to_string().contains("reconstruction"));
+        assert_eq!(
+            err,
+            FastCryptoError::WithContext {
+                context: "reconstruction".to_string(),
+                source: Box::new(FastCryptoError::InvalidInput),
+            }
+        );
+        // the original variant is kept, also through several layers
+        let err = Err::<(), _>(err).with_context("dkg").unwrap_err();
+        assert_eq!(err.without_context(), &FastCryptoError::InvalidInput);
+        assert_eq!(
+            Poly::<S>::recover_c0(threshold, shares).with_context("reconstruction"),
+            Ok(*poly.c0())
+        );
+    }
+
+    #[test]
+    fn test_recover_c0_with_index_scalars<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let index_scalars = Poly::<S>::index_scalars(8);
+        assert_eq!(index_scalars[2], S::from(3));
+        // overlapping sets of indices, partially outside of the precomputed scalars
+        for start in [1, 3, 6] {
+            let shares = (start..start + threshold)
+                .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                Poly::<S>::recover_c0_with_index_scalars(threshold, shares.iter(), &index_scalars)
+                    .unwrap(),
+                Poly::<S>::recover_c0(threshold, shares.iter()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_recover_c0_with_progress<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        let mut reported = Vec::new();
+        let c0 =
+            Poly::<S>::recover_c0_with_progress(threshold, shares.clone(), |k| reported.push(k))
+                .unwrap();
+        assert_eq!(&c0, poly.c0());
+        assert_eq!(reported, (1..=threshold as usize).collect::<Vec<_>>());
+
+        let mut calls = 0;
+        assert!(
+            Poly::<S>::recover_c0_with_progress(threshold, shares.skip(1), |_| calls += 1).is_err()
+        );
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn test_recover_all_evals<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+        let domain = (20..20 + threshold as u128)
+            .map(S::from)
+            .chain(std::iter::once(S::zero()))
+            .collect::<Vec<_>>();
+        let evals = Poly::<S>::recover_all_evals(threshold, shares.iter(), &domain).unwrap();
+        assert_eq!(evals.len(), domain.len());
+        for (x, e) in domain.iter().zip(&evals) {
+            assert_eq!(e, &poly.eval_at_scalar(x));
+        }
+        assert_eq!(evals.last(), Some(poly.c0()));
+
+        // From evaluation form back to the secret.
+        let evals = (20..20 + threshold)
+            .zip(evals)
+            .map(|(i, value)| Eval {
+                index: ShareIndex::new(i).unwrap(),
+                value,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            &Poly::<S>::recover_c0(threshold, evals.iter()).unwrap(),
+            poly.c0()
+        );
+
+        assert!(Poly::<S>::recover_all_evals(threshold, shares.iter().skip(1), &domain).is_err());
+        let duplicates = shares.iter().take(4).chain(shares.iter().take(1));
+        assert!(Poly::<S>::recover_all_evals(threshold, duplicates, &domain).is_err());
+    }
+
+    #[test]
+    fn test_select_subset<S: Scalar>() {
+        let threshold = 4;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=10)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+        let subset = Poly::<S>::select_subset(threshold, shares.iter()).unwrap();
+        assert_eq!(subset, shares[..threshold as usize]);
+
+        let mut shuffled = shares.clone();
+        shuffled.shuffle(&mut thread_rng());
+        assert_eq!(
+            Poly::<S>::select_subset(threshold, shuffled.iter()).unwrap(),
+            subset
+        );
+        let reversed = shares.iter().rev().chain(shares.iter());
+        assert_eq!(
+            Poly::<S>::select_subset(threshold, reversed).unwrap(),
+            subset
+        );
+        assert_eq!(
+            Poly::<S>::select_subset(threshold, shares.iter().take(3).chain(shares.iter())),
+            Ok(subset)
+        );
+        assert_eq!(
+            Poly::<S>::select_subset(threshold, shares.iter().take(3).cycle().take(10)),
+            Err(FastCryptoError::NotEnoughInputs)
+        );
+
+        // An equivocated share is rejected independently of which value comes first.
+        let mut equivocated = shares[1].clone();
+        equivocated.value += S::generator();
+        for pair in [
+            [shares[1].clone(), equivocated.clone()],
+            [equivocated, shares[1].clone()],
+        ] {
+            let conflicting = pair.iter().chain(shares.iter());
+            assert_eq!(
+                Poly::<S>::select_subset(threshold, conflicting.clone()),
+                Err(FastCryptoError::InvalidInput)
+            );
+            assert_eq!(
+                Poly::<S>::select_subset_min_spread(threshold, conflicting.clone()),
+                Err(FastCryptoError::InvalidInput)
+            );
+            assert_eq!(
+                Poly::<S>::recover_c0_with_slack(threshold, conflicting),
+                Err(FastCryptoError::InvalidInput)
+            );
+        }
+    }
+
+    #[test]
+    fn test_recover_c0_from_map<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold + 2)
+            .rev()
+            .map(|i| {
+                let e = poly.eval(ShareIndex::new(i).unwrap());
+                (e.index, e.value)
+            })
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(
+            &Poly::<S>::recover_c0_from_map(threshold, &shares).unwrap(),
+            poly.c0()
+        );
+        let shares = shares.into_iter().take(threshold as usize - 1).collect();
+        assert_eq!(
+            Poly::<S>::recover_c0_from_map(threshold, &shares),
+            Err(FastCryptoError::NotEnoughInputs)
+        );
+    }
+
+    #[test]
+    fn test_from_reconstructed_coefficients<S: Scalar>() {
+        let t = 3;
+        let p = Poly::<S>::rand(4, &mut thread_rng());
+        // Each coefficient is shared with a polynomial of degree t-1.
+        let sub_shares = p
+            .as_vec()
+            .iter()
+            .map(|c| {
+                let mut sharing = Poly::<S>::rand(t - 1, &mut thread_rng());
+                sharing[0] = *c;
+                (2..=t + 1)
+                    .map(|i| sharing.eval(ShareIndex::new(i).unwrap()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let reconstructed = Poly::<S>::from_reconstructed_coefficients(&sub_shares, t).unwrap();
+        assert_eq!(reconstructed, p);
+        assert_eq!(reconstructed.eval(I10), p.eval(I10));
+
+        let mut missing = sub_shares.clone();
+        missing[2].pop();
+        assert_eq!(
+            Poly::<S>::from_reconstructed_coefficients(&missing, t),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::<S>::from_reconstructed_coefficients(&[], t),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_cmp_by_index<S: Scalar>() {
+        let poly = Poly::<S>::rand(4, &mut thread_rng());
+        let mut shares = (1..=20)
+            .map(|i| poly.eval(ShareIndex::new(3 * i).unwrap()))
+            .collect::<Vec<_>>();
+        let sorted = shares.clone();
+        shares.shuffle(&mut thread_rng());
+        shares.sort_by(Eval::cmp_by_index);
+        assert_eq!(shares, sorted);
+
+        let position = shares.binary_search_by_key(&ShareIndex::new(30).unwrap(), |s| s.index);
+        assert_eq!(position, Ok(9));
+        assert_eq!(shares[9], poly.eval(ShareIndex::new(30).unwrap()));
+        assert!(shares
+            .binary_search_by_key(&ShareIndex::new(31).unwrap(), |s| s.index)
+            .is_err());
+        assert_eq!(shares[0].cmp_by_index(&shares[1]), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn test_bytes_with_explicit_order<S: Scalar + ToFromByteArray<32>>() {
+        // Eval with index 10 and value 1.
+        let one = Eval {
+            index: I10,
+            value: S::generator(),
+        };
+        let mut expected = vec![0u8; 36];
+        expected[3] = 10;
+        expected[35] = 1;
+        assert_eq!(one.to_bytes_be(), expected);
+        expected.reverse();
+        assert_eq!(one.to_bytes_le()[..4], expected[32..]);
+        assert_eq!(one.to_bytes_le()[4..], expected[..32]);
+
+        let share = Poly::<S>::rand(3, &mut thread_rng()).eval(I10);
+        let be = share.to_bytes_be();
+        let le = share.to_bytes_le();
+        assert_eq!(Eval::<S>::from_bytes_be(&be).unwrap(), share);
+        assert_eq!(Eval::<S>::from_bytes_le(&le).unwrap(), share);
+        // Each field is reversed.
+        assert!(be[..4].iter().eq(le[..4].iter().rev()));
+        assert!(be[4..].iter().eq(le[4..].iter().rev()));
+        assert!(Eval::<S>::from_bytes_be(&be[1..]).is_err());
+        assert!(Eval::<S>::from_bytes_be(&[0u8; 36]).is_err());
+
+        let p = Poly::<S>::rand(3, &mut thread_rng());
+        let be = p.to_bytes_be();
+        let le = p.to_bytes_le();
+        assert_eq!(be.len(), 4 * 32);
+        assert_eq!(Poly::<S>::from_bytes_be(&be).unwrap(), p);
+        assert_eq!(Poly::<S>::from_bytes_le(&le).unwrap(), p);
+        for (b, l) in be.chunks(32).zip(le.chunks(32)) {
+            assert!(b.iter().eq(l.iter().rev()));
+        }
+        let c0 = Eval {
+            index: I10,
+            value: p[0],
+        };
+        assert_eq!(be[..32], c0.to_bytes_be()[4..]);
+        assert!(Poly::<S>::from_bytes_be(&be[1..]).is_err());
+        assert!(Poly::<S>::from_bytes_be(&[]).is_err());
+        assert!(Poly::<S>::from_bytes_be(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn test_blinding_to<S: Scalar>() {
+        let share = Poly::<S>::rand(3, &mut thread_rng()).eval(I10);
+        let target = S::rand(&mut thread_rng());
+        let blinding = share.blinding_to(&target).unwrap();
+        assert_eq!(share.value + blinding, target);
+        assert_eq!(share.blinding_to(&share.value).unwrap(), S::zero());
+    }
+
+    #[test]
+    fn test_eval_from_bytes_canonical<S: Scalar>() {
+        let share = Poly::<S>::rand(3, &mut thread_rng()).eval(I10);
+        let bytes = bcs::to_bytes(&share).unwrap();
+        assert_eq!(Eval::<S>::from_bytes_canonical(&bytes).unwrap(), share);
+
+        // The value is larger than the group order.
+        let mut non_canonical = bytes.clone();
+        non_canonical[4..].fill(0xff);
+        assert_eq!(
+            Eval::<S>::from_bytes_canonical(&non_canonical),
+            Err(FastCryptoError::InvalidInput)
+        );
+        // Trailing bytes and a zero index.
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(Eval::<S>::from_bytes_canonical(&trailing).is_err());
+        let mut zero_index = bytes.clone();
+        zero_index[..4].fill(0);
+        assert!(Eval::<S>::from_bytes_canonical(&zero_index).is_err());
+    }
+
+    #[test]
+    fn test_recover_c0_try<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let encoded = (1..=threshold)
+            .map(|i| bcs::to_bytes(&poly.eval(ShareIndex::new(i).unwrap())).unwrap())
+            .collect::<Vec<_>>();
+        let decode = |bytes: &Vec<u8>| {
+            bcs::from_bytes::<Eval<S>>(bytes).map_err(|_| FastCryptoError::InvalidInput)
+        };
+        assert_eq!(
+            &Poly::<S>::recover_c0_try(threshold, encoded.iter().map(decode)).unwrap(),
+            poly.c0()
+        );
+
+        // The first decoding error is returned, and later shares are not decoded.
+        let mut corrupted = encoded.clone();
+        corrupted[2].truncate(3);
+        let mut decoded = 0;
+        let result = Poly::<S>::recover_c0_try(
+            threshold,
+            corrupted.iter().map(|bytes| {
+                decoded += 1;
+                decode(bytes)
+            }),
+        );
+        assert_eq!(result, Err(FastCryptoError::InvalidInput));
+        assert_eq!(decoded, 3);
+
+        let result =
+            Poly::<S>::recover_c0_try(
+                threshold,
+                encoded.iter().map(decode).chain(std::iter::once(Err(
+                    FastCryptoError::GeneralError("source closed".to_string()),
+                ))),
+            );
+        assert_eq!(
+            result,
+            Err(FastCryptoError::GeneralError("source closed".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_recover_c0_dedup<S: Scalar>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+
+        // identical duplicates are accepted
+        let duplicated = shares
+            .iter()
+            .chain(shares.iter().take(2))
+            .chain(&shares[3..4]);
+        assert_eq!(
+            &Poly::<S>::recover_c0_dedup(threshold, duplicated.clone()).unwrap(),
+            poly.c0()
+        );
+        assert_eq!(
+            Poly::<S>::recover_c0(threshold, duplicated),
+            Err(FastCryptoError::InvalidInput)
+        );
+
+        // conflicting duplicates are rejected
+        let mut conflicting = shares[1].clone();
+        conflicting.value += S::generator();
+        assert_eq!(
+            Poly::<S>::recover_c0_dedup(threshold, shares.iter().chain([&conflicting])),
+            Err(FastCryptoError::InvalidInput)
+        );
+
+        // too few unique shares
+        assert_eq!(
+            Poly::<S>::recover_c0_dedup(threshold, shares[1..].iter().chain(&shares[1..2])),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_recover_secret<S: Scalar + Zeroize>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        let secret = Poly::<S>::recover_secret(threshold, shares.clone()).unwrap();
+        assert_eq!(*secret, *poly.c0());
+        assert_eq!(format!("{:?}", secret), "Secret(<elided>)");
+        assert!(Poly::<S>::recover_secret(threshold, shares.skip(1)).is_err());
+
+        // Dropping zeroizes the secret. ManuallyDrop keeps the memory accessible after the drop.
+        let mut secret = std::mem::ManuallyDrop::new(secret);
+        unsafe { std::mem::ManuallyDrop::drop(&mut secret) };
+        assert_eq!(**secret, S::zero());
+    }
+
+    #[test]
+    fn test_recover_c0_map<S: Scalar + Zeroize>() {
+        #[derive(Debug, PartialEq)]
+        struct DerivedKey(Vec<u8>);
+
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        let key = Poly::<S>::recover_c0_map(threshold, shares.clone(), |secret| {
+            DerivedKey(bcs::to_bytes(&(b"key", secret)).unwrap())
+        })
+        .unwrap();
+        assert_eq!(
+            key,
+            DerivedKey(bcs::to_bytes(&(b"key", *poly.c0())).unwrap())
+        );
+        assert!(Poly::<S>::recover_c0_map(threshold, shares.skip(1), |_| ()).is_err());
+    }
+
+    #[test]
+    fn test_recover_c0_bytes<S: Scalar + Zeroize>() {
+        let threshold = 5;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let shares = (1..=threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
+        let bytes = Poly::<S>::recover_c0_bytes(threshold, shares.clone()).unwrap();
+        assert_eq!(&bcs::from_bytes::<S>(&bytes).unwrap(), poly.c0());
+        assert!(Poly::<S>::recover_c0_bytes(threshold, shares.skip(1)).is_err());
+    }
+
+    #[test]
+    fn test_recover_c0_robust<S: Scalar>() {
+        let (t, n) = (4, 10);
+        let poly = Poly::<S>::rand(t - 1, &mut thread_rng());
+        let mut shares = (1..=n)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+
+        // fast path, with exactly t and with surplus shares
+        assert_eq!(
+            Poly::<S>::recover_c0_robust(t, n, shares.iter().take(t as usize)).unwrap(),
+            *poly.c0()
+        );
+        assert_eq!(
+            Poly::<S>::recover_c0_robust(t, n, shares.iter()).unwrap(),
+            *poly.c0()
+        );
+
+        // correction path, up to (10 - 4) / 2 = 3 errors (including in the first t shares)
+        shares[0].value += S::generator();
+        shares[5].value = S::zero();
+        shares[9].value = S::rand(&mut thread_rng());
+        assert_eq!(
+            Poly::<S>::recover_c0_robust(t, n, shares.iter()).unwrap(),
+            *poly.c0()
+        );
+
+        // unrecoverable, since only one surplus share (which includes the error in shares[0])
+        assert_eq!(
+            Poly::<S>::recover_c0_robust(t, n, shares.iter().take(5)),
+            Err(FastCryptoError::NotEnoughInputs)
+        );
+
+        shares.shuffle(&mut thread_rng());
+        assert_eq!(
+            Poly::<S>::recover_c0_robust(t, n, shares.iter()).unwrap(),
+            *poly.c0()
+        );
+
+        // unrecoverable, since there are more than 3 errors
+        let mut too_many_errors = (1..=n)
+            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+        too_many_errors
+            .iter_mut()
+            .take(4)
+            .for_each(|s| s.value += S::generator());
+        assert!(Poly::<S>::recover_c0_robust(t, n, too_many_errors.iter()).is_err());
+
+        // invalid inputs
+        assert_eq!(
+            Poly::<S>::recover_c0_robust(t, n - 1, shares.iter()),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::<S>::recover_c0_robust(t, n, shares.iter().chain(shares.iter().take(1))),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_relabel_by_reevaluation<S: Scalar>() {
+        let threshold = 4;
+        let poly = Poly::<S>::rand(threshold - 1, &mut thread_rng());
+        let mapping = (1..=threshold)
+            .map(|i| {
+                (
+                    ShareIndex::new(i).unwrap(),
+                    ShareIndex::new(100 + i).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let relabeled = poly.relabel_by_reevaluation(&mapping).unwrap();
+        for ((_, new), share) in mapping.iter().zip(relabeled.iter()) {
+            assert_eq!(share.index, *new);
+            assert_eq!(share, &poly.eval(*new));
+        }
+        assert_eq!(
+            &Poly::<S>::recover_c0(threshold, relabeled.iter()).unwrap(),
+            poly.c0()
+        );
+        // duplicate new indices
+        let mapping = vec![
+            (ShareIndex::new(1).unwrap(), ShareIndex::new(3).unwrap()),
+            (ShareIndex::new(2).unwrap(), ShareIndex::new(3).unwrap()),
+        ];
+        assert!(poly.relabel_by_reevaluation(&mapping).is_err());
+    }
+
+    #[test]
+    fn test_split<S: Scalar>() {
+        let secret = S::rand(&mut thread_rng());
+        let shares = Poly::<S>::split(secret, 3, 5, false, &mut thread_rng()).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert_eq!(
+            Poly::<S>::recover_c0(3, shares.iter().skip(2)).unwrap(),
+            secret
+        );
+
+        assert!(Poly::<S>::split(secret, 0, 5, false, &mut thread_rng()).is_err());
+        assert!(Poly::<S>::split(secret, 6, 5, false, &mut thread_rng()).is_err());
+    }
+
+    #[test]
+    fn test_split_trivial<S: Scalar>() {
+        let secret = S::rand(&mut thread_rng());
+        assert_eq!(
+            Poly::<S>::split(secret, 1, 5, false, &mut thread_rng()),
+            Err(FastCryptoError::InvalidInput)
+        );
+
+        // with the opt-out, every share is the secret itself
+        let shares = Poly::<S>::split(secret, 1, 5, true, &mut thread_rng()).unwrap();
+        assert!(shares.iter().all(|s| s.value == secret));
+    }
+
+    #[instantiate_tests(<RistrettoScalar>)]
+    mod ristretto_scalar {}
+
+    #[instantiate_tests(<BlsScalar>)]
+    mod bls_scalar {}
+}
+
+#[generic_tests::define]
+mod points_tests {
+    use super::*;
+    use itertools::{Either, Itertools};
+    #[test]
+    fn test_eval_and_commit<G: GroupElement>() {
+        // test zero
+        let p = Poly::<G::ScalarType>::zero();
+        assert_eq!(p.eval(I10).value, G::ScalarType::zero());
+        // test consistency
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let e1 = p.eval(I10);
+        let public_p = p.commit();
+        let e2 = public_p.eval(I10);
+        assert_eq!(e1.index, e2.index);
+        assert_eq!(G::generator() * e1.value, e2.value);
+        assert!(public_p.verify_share(e1.index, &e1.value).is_ok());
+        // test simple poly
+        let one = G::ScalarType::generator();
+        let coeff = vec![one, one, one];
+        let p = Poly::<G::ScalarType>::from(coeff);
+        assert_eq!(p.degree(), 2);
+        let s1 = p.eval(NonZeroU32::new(10).unwrap());
+        let s2 = p.eval(NonZeroU32::new(20).unwrap());
+        let s3 = p.eval(NonZeroU32::new(30).unwrap());
+        let shares = vec![s1, s2, s3];
+        assert_eq!(
+            Poly::<G::ScalarType>::recover_c0(3, shares.iter()).unwrap(),
+            one
+        );
+    }
+
+    #[test]
+    fn test_eval_cache_public<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let expected = G::generator() * p.eval(I10).value;
+        assert_eq!(public_p.eval(I10).value, expected);
+        assert_eq!(public_p.eval(I10).value, expected);
+        #[cfg(feature = "cached")]
+        {
+            assert_eq!(public_p.cached_evals(), 1);
+            assert_eq!(p.cached_evals(), 0);
+
+            // Large indices are not memoized, thus the cache is bounded.
+            for i in EVAL_CACHE_MAX_INDEX - 1..EVAL_CACHE_MAX_INDEX + 100 {
+                let i = ShareIndex::new(i).unwrap();
+                assert_eq!(public_p.eval(i), public_p.eval(i));
+            }
+            assert_eq!(public_p.cached_evals(), 3);
+        }
+    }
+
+    #[test]
+    fn test_verify_share_returning_pub<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let share = p.eval(I10);
+        assert_eq!(
+            public_p
+                .verify_share_returning_pub(share.index, &share.value)
+                .unwrap(),
+            G::generator() * share.value
+        );
+        assert!(public_p
+            .verify_share_returning_pub(share.index, &(share.value + G::ScalarType::generator()))
+            .is_err());
+    }
+
+    #[test]
+    fn test_checked_add_same_type<G: GroupElement>() {
+        let p1 = Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>();
+        let p2 = Poly::<G::ScalarType>::rand(5, &mut thread_rng()).commit::<G>();
+        let mut expected = p1.clone();
+        expected.add(&p2);
+
+        let mut sum = p1.clone();
+        assert!(sum.checked_add_same_type(&p2, |_| "curve A").is_ok());
+        assert_eq!(sum, expected);
+
+        // Mocked parameters where one coefficient of p2 is from another configuration.
+        let other = p2[4];
+        let mut sum = p1.clone();
+        assert_eq!(
+            sum.checked_add_same_type(&p2, |c| if *c == other { "curve B" } else { "curve A" }),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(sum, p1);
+    }
+
+    #[test]
+    fn test_share_set_fingerprint<G: GroupElement + Serialize>() {
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>();
+        let mut shares = (1..=6)
+            .map(|i| p.eval(ShareIndex::new(i).unwrap()))
+            .collect::<Vec<_>>();
+        let fingerprint = Poly::share_set_fingerprint(&shares);
+        shares.shuffle(&mut thread_rng());
+        assert_eq!(Poly::share_set_fingerprint(&shares), fingerprint);
+
+        let mut changed = shares.clone();
+        changed[2].value += G::generator();
+        assert_ne!(Poly::share_set_fingerprint(&changed), fingerprint);
+        assert_ne!(Poly::share_set_fingerprint(&shares[1..]), fingerprint);
+        let mut relabeled = shares.clone();
+        relabeled[0].index = ShareIndex::new(7).unwrap();
+        assert_ne!(Poly::share_set_fingerprint(&relabeled), fingerprint);
+    }
+
+    #[test]
+    fn test_transcript_hash<G: GroupElement + Serialize>() {
+        let p1 = Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>();
+        let p2 = Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>();
+        let h = p1.transcript_hash(b"session 1");
+        assert_eq!(h, p1.clone().transcript_hash(b"session 1"));
+        assert_ne!(h, p1.transcript_hash(b"session 2"));
+        assert_ne!(h, p2.transcript_hash(b"session 1"));
+        assert_ne!(p1.transcript_hash(b""), p1.transcript_hash(&[0]));
+    }
+
+    #[test]
+    fn test_hermite_interpolate<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let derivative = Poly::<G::ScalarType>::from(
+            p.as_vec()
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| *c * G::ScalarType::from(i as u128))
+                .collect_vec(),
+        );
+        let public_p = p.commit::<G>();
+        let public_derivative = derivative.commit::<G>();
+
+        // Three points with values and derivatives determine a polynomial of degree 5.
+        let points = [3u128, 7, 11]
+            .iter()
+            .map(|x| {
+                let x = G::ScalarType::from(*x);
+                (
+                    x,
+                    public_p.eval_at_scalar(&x),
+                    public_derivative.eval_at_scalar(&x),
+                )
+            })
+            .collect_vec();
+        assert_eq!(Poly::<G>::hermite_interpolate(&points).unwrap(), public_p);
+
+        // Fewer points give a polynomial of lower degree that still matches the given points.
+        let partial = Poly::<G>::hermite_interpolate(&points[..2]).unwrap();
+        assert_eq!(partial.degree(), 3);
+        assert_ne!(partial, public_p);
+        for (x, value, _) in &points[..2] {
+            assert_eq!(&partial.eval_at_scalar(x), value);
+        }
+
+        assert!(Poly::<G>::hermite_interpolate(&[]).is_err());
+        assert!(Poly::<G>::hermite_interpolate(&[points[0], points[1], points[0]]).is_err());
+    }
+
+    #[test]
+    fn test_recover_c0_checked_against<G: GroupElement>() {
+        let t = 4;
+        let p = Poly::<G::ScalarType>::rand(t - 1, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let shares = (1..=t + 1)
+            .map(|i| public_p.eval(ShareIndex::new(i).unwrap()))
+            .collect_vec();
+        assert_eq!(
+            &Poly::<G>::recover_c0_checked_against(&public_p, t, shares[..4].iter()).unwrap(),
+            public_p.c0()
+        );
+        assert_eq!(
+            Poly::<G>::recover_c0_checked_against(&public_p, t + 1, shares.iter()),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::<G>::recover_c0_checked_against(&public_p, 0, shares[..0].iter()),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_eval_estrin<G: GroupElement>() {
+        for degree in [0, 1, 2, 5, 8, 16] {
+            let p = Poly::<G::ScalarType>::rand(degree, &mut thread_rng());
+            let public_p = p.commit::<G>();
+            for i in [1, 2, 10, 123_456, u32::MAX] {
+                let i = ShareIndex::new(i).unwrap();
+                assert_eq!(p.eval_estrin(i), p.eval(i));
+                assert_eq!(public_p.eval_estrin(i), public_p.eval(i));
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_reshare_subshares<G: GroupElement>() {
+        // The party with index 10 reshares its share.
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let mut reshare = Poly::<G::ScalarType>::rand(2, &mut thread_rng());
+        reshare[0] = p.eval(I10).value;
+        let reshare_public = reshare.commit::<G>();
+        let mut subshares = (1..=5)
+            .map(|i| reshare.eval(ShareIndex::new(i).unwrap()))
+            .collect_vec();
+        assert!(Poly::verify_reshare_subshares(&reshare_public, &subshares).is_ok());
+        assert!(Poly::verify_reshare_subshares(&reshare_public, &[]).is_ok());
+
+        subshares[2].value += G::ScalarType::generator();
+        assert_eq!(
+            Poly::verify_reshare_subshares(&reshare_public, &subshares),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert!(Poly::verify_reshare_subshares(&reshare_public, &subshares[..2]).is_ok());
+    }
+
+    #[test]
+    fn test_verify_reshare_chain<G: GroupElement>() {
+        let secret = G::ScalarType::rand(&mut thread_rng());
+        let mut chain = (0..5)
+            .map(|_| {
+                let mut p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+                p[0] = secret;
+                p.commit::<G>()
+            })
+            .collect::<Vec<_>>();
+        assert!(Poly::verify_reshare_chain(&chain).is_ok());
+        assert!(Poly::verify_reshare_chain(&chain[..1]).is_ok());
+        assert_eq!(
+            Poly::<G>::verify_reshare_chain(&[]),
+            Err(FastCryptoError::InvalidInput)
+        );
+
+        // Epoch 3 accidentally changed the secret.
+        let mut p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        p[0] = secret + G::ScalarType::generator();
+        chain[3] = p.commit::<G>();
+        assert_eq!(
+            Poly::verify_reshare_chain(&chain),
+            Err(FastCryptoError::GeneralError(
+                "epoch 3 changed the secret".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_empty_public_poly<G: GroupElement + MultiScalarMul + Serialize + DeserializeOwned>()
+    where
+        G::ScalarType: FiatShamirChallenge,
+    {
+        // Empty polynomials, e.g., from untrusted input, result in errors instead of panics.
+        let empty = bcs::from_bytes::<Poly<G>>(&[0]).unwrap();
+        let share = (I10, G::ScalarType::generator());
+        assert_eq!(
+            empty.verify_shares_fiat_shamir(&[share], b"test"),
+            Err(FastCryptoError::InvalidInput)
+        );
+        let shares = [Eval {
+            index: I10,
+            value: G::generator(),
+        }];
+        assert_eq!(
+            Poly::<G>::recover_c0_checked_against(&empty, 1, shares.iter()),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            empty.eval_committee_msm(5),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            empty.verify_secret_commitment(&G::zero()),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(empty.c0_bytes(), Err(FastCryptoError::InvalidInput));
+
+        // Dealings, e.g., received from other parties, of which one is empty.
+        let p = Poly::<G::ScalarType>::rand(0, &mut thread_rng()).commit::<G>();
+        let dealings = [p.clone(), empty.clone()];
+        assert_eq!(
+            Poly::contributions(&dealings),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::aggregate_key(&dealings),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::finalize_dkg(&[empty.clone(), empty.clone()]),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::verify_reshare_chain(&dealings),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::verify_resharing(&p, &[empty.clone()], &[I10]),
+            Err(FastCryptoError::InvalidInput)
+        );
+        let reshare = Poly::from(vec![p.eval(I10).value]);
+        assert_eq!(
+            Poly::verify_resharing(&empty, &[reshare], &[I10]),
+            Err(FastCryptoError::InvalidInput)
+        );
+
+        let ro = RandomOracle::new("test");
+        let a = Poly::<G::ScalarType>::rand(1, &mut thread_rng());
+        let proof = Poly::<G>::prove_equal_c0(&a, &a, &ro, &mut thread_rng()).unwrap();
+        assert_eq!(
+            Poly::verify_equal_c0(&empty, &a.commit::<G>(), &proof, &ro),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::verify_equal_c0(&a.commit::<G>(), &empty, &proof, &ro),
+            Err(FastCryptoError::InvalidInput)
+        );
+
+        let merkle = MerklePoly::new(empty);
+        assert_eq!(merkle.open(0), Err(FastCryptoError::InvalidInput));
+        assert!(merkle.eval_with_paths(I10).1.is_empty());
+    }
+
+    #[test]
+    fn test_merkle_poly<G: GroupElement + Serialize>() {
+        for degree in [0, 1, 2, 4, 7] {
+            let p = Poly::<G::ScalarType>::rand(degree, &mut thread_rng()).commit::<G>();
+            let merkle = MerklePoly::new(p.clone());
+            let root = merkle.root();
+            assert_eq!(merkle.poly(), &p);
+
+            for (k, c) in p.as_vec().iter().enumerate() {
+                let path = merkle.open(k as u32).unwrap();
+                assert!(MerklePoly::verify_path(&root, degree, k as u32, c, &path).is_ok());
+                // Wrong coefficient, position or degree.
+                assert_eq!(
+                    MerklePoly::verify_path(&root, degree, k as u32, &(*c + G::generator()), &path),
+                    Err(FastCryptoError::InvalidProof)
+                );
+                if degree > 0 {
+                    let other = (k as u32 + 1) % (degree + 1);
+                    assert!(MerklePoly::verify_path(&root, degree, other, c, &path).is_err());
+                }
+                assert!(MerklePoly::verify_path(&root, degree + 1, k as u32, c, &path).is_err());
+            }
+            assert_eq!(merkle.open(degree + 1), Err(FastCryptoError::InvalidInput));
+
+            let (eval, paths) = merkle.eval_with_paths(I10);
+            assert_eq!(eval, p.eval(I10));
+            assert!(MerklePoly::verify_eval(&root, &eval, p.as_vec(), &paths).is_ok());
+
+            // Tampered evaluation, path and coefficients.
+            let mut wrong_eval = eval.clone();
+            wrong_eval.value += G::generator();
+            assert_eq!(
+                MerklePoly::verify_eval(&root, &wrong_eval, p.as_vec(), &paths),
+                Err(FastCryptoError::InvalidProof)
+            );
+            if degree > 0 {
+                let mut tampered = paths.clone();
+                tampered[0] = paths[1].clone();
+                assert!(MerklePoly::verify_eval(&root, &eval, p.as_vec(), &tampered).is_err());
+                let truncated = &p.as_vec()[..degree as usize];
+                assert!(MerklePoly::verify_eval(
+                    &root,
+                    &eval,
+                    truncated,
+                    &paths[..degree as usize]
+                )
+                .is_err());
+            }
+            assert!(MerklePoly::verify_eval(&root, &eval, &[], &[]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_verify_coefficient<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        for (k, c) in p.as_vec().iter().enumerate() {
+            assert!(public_p.verify_coefficient(k as u32, c).is_ok());
+        }
+        assert_eq!(
+            public_p.verify_coefficient(1, &p[2]),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            public_p.verify_coefficient(1, &(p[1] + G::ScalarType::generator())),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            public_p.verify_coefficient(4, &p[3]),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_c0_recovery<G: GroupElement>() {
+        let t = 5;
+        let p = Poly::<G::ScalarType>::rand(t - 1, &mut thread_rng()).commit::<G>();
+        let shares = (1..=8)
+            .map(|i| p.eval(ShareIndex::new(i).unwrap()))
+            .collect_vec();
+
+        let mut recovery = C0Recovery::new(t);
+        assert!(recovery.is_empty());
+        recovery.extend(shares[5..].iter().cloned());
+        assert_eq!(recovery.finalize(), Err(FastCryptoError::NotEnoughInputs));
+        // Repeated shares are ignored.
+        recovery.extend(shares[4..7].iter().cloned());
+        assert_eq!(recovery.len(), 4);
+        assert!(!recovery.is_complete());
+        recovery.extend(shares[..2].iter().cloned());
+        assert!(recovery.is_complete());
+        assert_eq!(recovery.finalize().unwrap(), *p.c0());
+        assert_eq!(
+            recovery.finalize().unwrap(),
+            Poly::<G>::recover_c0(t, shares.iter().take(t as usize)).unwrap()
+        );
+
+        // A conflicting share makes the recovery fail.
+        let mut conflicting = shares[0].clone();
+        conflicting.value += G::generator();
+        assert_eq!(
+            recovery.add(conflicting.clone()),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(recovery.finalize(), Err(FastCryptoError::InvalidInput));
+        let mut recovery = C0Recovery::new(t);
+        recovery.extend(shares.iter().cloned().chain(std::iter::once(conflicting)));
+        assert_eq!(recovery.finalize(), Err(FastCryptoError::InvalidInput));
+    }
+
+    #[test]
+    fn test_recover_all_then_eval<G: GroupElement>() {
+        for t in [1, 2, 5] {
+            let p = Poly::<G::ScalarType>::rand(t - 1, &mut thread_rng()).commit::<G>();
+            let shares = [3, 8, 1, 20, 9]
+                .iter()
+                .take(t as usize)
+                .map(|i| p.eval(ShareIndex::new(*i).unwrap()))
+                .collect_vec();
+            let new_indices = [I10, ShareIndex::new(31).unwrap()];
+            let (recovered, new_shares) =
+                Poly::<G>::recover_all_then_eval(t, shares.iter(), &new_indices).unwrap();
+            assert_eq!(recovered, p);
+            assert_eq!(
+                new_shares,
+                vec![p.eval(new_indices[0]), p.eval(new_indices[1])]
+            );
+        }
+
+        let p = Poly::<G::ScalarType>::rand(2, &mut thread_rng()).commit::<G>();
+        let shares = (1..=3)
+            .map(|i| p.eval(ShareIndex::new(i).unwrap()))
+            .collect_vec();
+        assert!(Poly::<G>::recover_all_then_eval(4, shares.iter(), &[I10]).is_err());
+        assert!(Poly::<G>::recover_all_then_eval(
+            3,
+            [&shares[0], &shares[1], &shares[0]].into_iter(),
+            &[I10]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_lde_commitment<G: GroupElement>() {
+        let t = 3;
+        let p = Poly::<G::ScalarType>::rand(t - 1, &mut thread_rng()).commit::<G>();
+        let mut shares = (1..=7)
+            .map(|i| p.eval(ShareIndex::new(i).unwrap()))
+            .collect_vec();
+        let lde = Poly::<G>::lde_commitment(&shares, t, 16).unwrap();
+        assert_eq!(lde.len(), 16);
+        for (i, e) in lde.iter().enumerate() {
+            assert_eq!(*e, p.eval(ShareIndex::new(i as u32 + 1).unwrap()).value);
+        }
+        assert!(shares
+            .iter()
+            .all(|s| lde[s.index.get() as usize - 1] == s.value));
+
+        // The shares with the smallest indices are used, independently of the order.
+        let mut shuffled = shares.clone();
+        shuffled.reverse();
+        assert_eq!(Poly::<G>::lde_commitment(&shuffled, t, 16).unwrap(), lde);
 
-        let p1 = Poly::<S>::rand(3, &mut thread_rng());
-        let p2 = Poly::<S>::rand(5, &mut thread_rng());
-        let mut p3 = p1.clone();
-        p3.add(&p2);
-        assert_eq!(p1.eval(I10).value + p2.eval(I10).value, p3.eval(I10).value);
+        // An inconsistent share (beyond the first t) does not match the extension.
+        shares[5].value += G::generator();
+        let lde = Poly::<G>::lde_commitment(&shares, t, 16).unwrap();
+        assert_ne!(lde[5], shares[5].value);
+        shares.swap(0, 5);
+        assert_eq!(Poly::<G>::lde_commitment(&shares, t, 16).unwrap(), lde);
+
+        // Duplicate indices are rejected, also after the first t shares.
+        let mut duplicates = shares.clone();
+        duplicates.push(shares[6].clone());
+        assert_eq!(
+            Poly::<G>::lde_commitment(&duplicates, t, 16),
+            Err(FastCryptoError::InvalidInput)
+        );
+
+        assert_eq!(
+            Poly::<G>::lde_commitment(&shares[..2], t, 16),
+            Err(FastCryptoError::NotEnoughInputs)
+        );
+        assert_eq!(
+            Poly::<G>::lde_commitment(&shares, t, 6),
+            Err(FastCryptoError::InvalidInput)
+        );
     }
 
     #[test]
-    fn test_recover_c0_errors<S: Scalar>() {
-        let degree = 4;
-        let threshold = degree + 1;
-        let poly = Poly::<S>::rand(4, &mut thread_rng());
-        // insufficient shares gathered
-        let shares = (1..threshold).map(|i| poly.eval(ShareIndex::new(i).unwrap()));
-        Poly::<S>::recover_c0(threshold, shares).unwrap_err();
-        // duplications
-        let shares = (1..=threshold)
-            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
-            .chain(std::iter::once(poly.eval(ShareIndex::new(1).unwrap()))); // duplicate value 1
-        Poly::<S>::recover_c0(threshold, shares).unwrap_err();
+    fn test_skeleton<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let c0 = *public_p.c0();
+        assert_eq!(public_p.skeleton(), (c0, 3));
+        assert_eq!(
+            Poly::<G>::from(vec![G::generator()]).skeleton(),
+            (G::generator(), 0)
+        );
     }
 
     #[test]
-    fn test_recover_c0<S: Scalar>() {
-        let poly = Poly::<S>::rand(123, &mut thread_rng());
-        // insufficient shares gathered
-        let mut shares = (1..300)
-            .map(|i| poly.eval(ShareIndex::new(i).unwrap()))
+    fn test_verify_secret_commitment<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let pk = G::generator() * p.c0();
+        assert!(public_p.verify_secret_commitment(&pk).is_ok());
+        assert_eq!(
+            public_p.verify_secret_commitment(&(pk + G::generator())),
+            Err(FastCryptoError::InvalidInput)
+        );
+        // The claim is on the evaluation at zero, not on any share.
+        assert!(public_p
+            .verify_secret_commitment(&public_p.eval(I10).value)
+            .is_err());
+    }
+
+    #[test]
+    fn test_exponentiate_base<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let share = p.eval(I10);
+        let base = G::generator() * G::ScalarType::rand(&mut thread_rng());
+        let partial = share.exponentiate_base(&base);
+        assert_eq!(partial.index, I10);
+        assert_eq!(partial.value, base * share.value);
+        assert_eq!(
+            share.exponentiate_base(&G::generator()),
+            p.commit::<G>().eval(I10)
+        );
+    }
+
+    #[test]
+    fn test_share_difference<G: GroupElement>() {
+        let p1 = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let p2 = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let (public_p1, public_p2) = (p1.commit::<G>(), p2.commit::<G>());
+
+        let diff = p1.eval(I10).sub(&p2.eval(I10)).unwrap();
+        assert_eq!(diff.index, I10);
+        assert_eq!(diff.value, p1.eval(I10).value - p2.eval(I10).value);
+        assert!(public_p1.verify_share_difference(&public_p2, &diff).is_ok());
+        assert!(public_p2
+            .verify_share_difference(&public_p1, &diff)
+            .is_err());
+
+        let other_index = ShareIndex::new(3).unwrap();
+        assert!(p1.eval(I10).sub(&p2.eval(other_index)).is_err());
+        let wrong_diff = Eval {
+            index: other_index,
+            value: diff.value,
+        };
+        assert!(public_p1
+            .verify_share_difference(&public_p2, &wrong_diff)
+            .is_err());
+    }
+
+    #[test]
+    fn test_deal_with_public<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let (public_p, shares) = p.deal_with_public::<G>(7).unwrap();
+        assert_eq!(public_p, p.commit::<G>());
+        assert_eq!(shares.len(), 7);
+        for (i, (share, public_share)) in (1..=7).zip(shares.iter()) {
+            let i = ShareIndex::new(i).unwrap();
+            assert_eq!(share, &p.eval(i));
+            assert_eq!(public_share.index, i);
+            assert_eq!(public_share.value, G::generator() * share.value);
+            assert_eq!(public_share, &public_p.eval(i));
+        }
+        assert!(p.deal_with_public::<G>(3).is_err());
+    }
+
+    #[test]
+    fn test_deal_private_and_public<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let (public_p, shares) = p.deal_private_and_public::<G>(7).unwrap();
+        assert_eq!(public_p, p.commit::<G>());
+        assert_eq!(shares.len(), 7);
+        for (i, share) in (1..=7).zip(shares.iter()) {
+            assert_eq!(share.index, ShareIndex::new(i).unwrap());
+            assert!(public_p.verify_share(share.index, &share.value).is_ok());
+        }
+        assert_eq!(
+            &Poly::<G::ScalarType>::recover_c0(4, shares.iter().skip(3)).unwrap(),
+            p.c0()
+        );
+        assert!(p.deal_private_and_public::<G>(3).is_err());
+    }
+
+    #[test]
+    fn test_verify_public_shares<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let mut public_shares = (1..=10)
+            .map(|i| {
+                let share = p.eval(ShareIndex::new(i).unwrap());
+                Eval {
+                    index: share.index,
+                    value: G::generator() * share.value,
+                }
+            })
             .collect::<Vec<_>>();
-        let c0 = poly.c0();
-        for _ in 0..10 {
-            shares.shuffle(&mut thread_rng());
-            let used_shares = shares.iter().take(124);
-            assert_eq!(c0, &Poly::<S>::recover_c0(124, used_shares).unwrap());
+        assert!(public_p.verify_public_shares(&public_shares).is_ok());
+        assert!(public_p
+            .find_invalid_public_shares(&public_shares)
+            .is_empty());
+
+        public_shares[2].value += G::generator();
+        public_shares[7].value = G::zero();
+        assert!(public_p.verify_public_shares(&public_shares).is_err());
+        assert_eq!(
+            public_p.find_invalid_public_shares(&public_shares),
+            vec![public_shares[2].index, public_shares[7].index]
+        );
+    }
+
+    #[test]
+    fn test_inner_product<G: GroupElement>() {
+        // p(x) = 1 + 2x + 3x^2 with weights (4, 5, 6) gives 1*4 + 2*5 + 3*6 = 32.
+        let p =
+            Poly::<G::ScalarType>::from((1..=3u128).map(G::ScalarType::from).collect::<Vec<_>>());
+        let weights = (4..=6u128).map(G::ScalarType::from).collect::<Vec<_>>();
+        let expected = G::ScalarType::from(32u128);
+        assert_eq!(p.inner_product(&weights).unwrap(), expected);
+        assert_eq!(
+            p.commit::<G>().inner_product(&weights).unwrap(),
+            G::generator() * expected
+        );
+        // Weights of x^i give the evaluation at x.
+        let x = G::ScalarType::from(10u128);
+        assert_eq!(
+            p.inner_product(&[G::ScalarType::generator(), x, x * x])
+                .unwrap(),
+            p.eval(I10).value
+        );
+        assert!(p.inner_product(&weights[..2]).is_err());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_add_par<G: GroupElement + Send + Sync>() {
+        let p1 = Poly::<G::ScalarType>::rand(500, &mut thread_rng()).commit::<G>();
+        let p2 = Poly::<G::ScalarType>::rand(700, &mut thread_rng()).commit::<G>();
+        for (a, b) in [(&p1, &p2), (&p2, &p1)] {
+            let mut serial = a.clone();
+            serial.add(b);
+            let mut parallel = a.clone();
+            parallel.add_par(b);
+            assert_eq!(serial, parallel);
         }
     }
 
-    #[instantiate_tests(<RistrettoScalar>)]
-    mod ristretto_scalar {}
+    #[test]
+    fn test_verify_my_shares<G: GroupElement + MultiScalarMul>() {
+        let my_index = ShareIndex::new(3).unwrap();
+        let mut entries = (0..5)
+            .map(|_| {
+                let p = Poly::<G::ScalarType>::rand(4, &mut thread_rng());
+                (p.commit::<G>(), p.eval(my_index).value)
+            })
+            .collect::<Vec<_>>();
+        assert!(Poly::verify_my_shares(my_index, &entries, &mut thread_rng()).is_ok());
+        assert!(Poly::<G>::verify_my_shares(my_index, &[], &mut thread_rng()).is_ok());
+        assert!(Poly::verify_my_shares(I10, &entries, &mut thread_rng()).is_err());
 
-    #[instantiate_tests(<BlsScalar>)]
-    mod bls_scalar {}
-}
+        entries[2].1 += G::ScalarType::generator();
+        assert!(Poly::verify_my_shares(my_index, &entries, &mut thread_rng()).is_err());
+    }
 
-#[generic_tests::define]
-mod points_tests {
-    use super::*;
-    use itertools::Either;
     #[test]
-    fn test_eval_and_commit<G: GroupElement>() {
-        // test zero
-        let p = Poly::<G::ScalarType>::zero();
-        assert_eq!(p.eval(I10).value, G::ScalarType::zero());
-        // test consistency
-        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
-        let e1 = p.eval(I10);
-        let public_p = p.commit();
-        let e2 = public_p.eval(I10);
-        assert_eq!(e1.index, e2.index);
-        assert_eq!(G::generator() * e1.value, e2.value);
-        assert!(public_p.verify_share(e1.index, &e1.value).is_ok());
-        // test simple poly
-        let one = G::ScalarType::generator();
-        let coeff = vec![one, one, one];
-        let p = Poly::<G::ScalarType>::from(coeff);
-        assert_eq!(p.degree(), 2);
-        let s1 = p.eval(NonZeroU32::new(10).unwrap());
-        let s2 = p.eval(NonZeroU32::new(20).unwrap());
-        let s3 = p.eval(NonZeroU32::new(30).unwrap());
-        let shares = vec![s1, s2, s3];
+    fn test_contributions<G: GroupElement>() {
+        let dealings = (0..5)
+            .map(|d| Poly::<G::ScalarType>::rand(d, &mut thread_rng()).commit::<G>())
+            .collect::<Vec<_>>();
+        let mut aggregate = Poly::<G>::zero();
+        dealings.iter().for_each(|p| aggregate.add(p));
+
+        let contributions = Poly::contributions(&dealings).unwrap();
+        assert_eq!(contributions.len(), dealings.len());
+        assert_eq!(&contributions[3], dealings[3].c0());
         assert_eq!(
-            Poly::<G::ScalarType>::recover_c0(3, shares.iter()).unwrap(),
-            one
+            contributions.iter().fold(G::zero(), |acc, c| acc + *c),
+            *aggregate.c0()
+        );
+        assert_eq!(Poly::aggregate_key(&dealings), Ok(*aggregate.c0()));
+        assert_eq!(Poly::<G>::aggregate_key(&[]), Ok(G::zero()));
+    }
+
+    #[test]
+    fn test_finalize_dkg<G: GroupElement>() {
+        let dealings = (0..4)
+            .map(|_| Poly::<G::ScalarType>::rand(2, &mut thread_rng()).commit::<G>())
+            .collect::<Vec<_>>();
+        let (aggregate, pk) = Poly::finalize_dkg(&dealings).unwrap();
+        assert_eq!(Ok(pk), Poly::aggregate_key(&dealings));
+        assert_eq!(&pk, aggregate.c0());
+        assert_eq!(aggregate.degree(), 2);
+        assert_eq!(
+            aggregate.eval(I10).value,
+            dealings
+                .iter()
+                .fold(G::zero(), |acc, d| acc + d.eval(I10).value)
+        );
+
+        let mut mixed = dealings.clone();
+        mixed.push(Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>());
+        assert_eq!(
+            Poly::finalize_dkg(&mixed),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::<G>::finalize_dkg(&[]),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_verify_aggregate_share<G: GroupElement>() {
+        let (t, n) = (3, 5);
+        let private_polys = (0..4)
+            .map(|_| Poly::<G::ScalarType>::rand(t - 1, &mut thread_rng()))
+            .collect::<Vec<_>>();
+        let mut aggregate_public = Poly::<G>::zero();
+        private_polys
+            .iter()
+            .for_each(|p| aggregate_public.add(&p.commit::<G>()));
+
+        let final_shares = (1..=n)
+            .map(|i| {
+                let index = ShareIndex::new(i).unwrap();
+                let received = private_polys
+                    .iter()
+                    .map(|p| p.eval(index).value)
+                    .collect::<Vec<_>>();
+                let share = Poly::<G>::aggregate_shares(&received);
+                assert!(Poly::verify_aggregate_share(&aggregate_public, index, &share).is_ok());
+                assert!(Poly::verify_aggregate_share(
+                    &aggregate_public,
+                    index,
+                    &(share + G::ScalarType::generator())
+                )
+                .is_err());
+                Eval {
+                    index,
+                    value: share,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The final shares are shares of the sum of the secrets.
+        let secret = private_polys
+            .iter()
+            .fold(G::ScalarType::zero(), |acc, p| acc + *p.c0());
+        assert_eq!(
+            Poly::<G::ScalarType>::recover_c0(t, final_shares.iter().skip(2)).unwrap(),
+            secret
+        );
+        assert_eq!(G::generator() * secret, *aggregate_public.c0());
+        assert_eq!(Poly::<G>::aggregate_shares(&[]), G::ScalarType::zero());
+    }
+
+    #[test]
+    fn test_verify_resharing<G: GroupElement>() {
+        let (old_t, new_t, new_n) = (3, 2, 4);
+        let old_private = Poly::<G::ScalarType>::rand(old_t - 1, &mut thread_rng());
+        let old_public = old_private.commit::<G>();
+        let old_indices = [1, 3, 4].map(|i| ShareIndex::new(i).unwrap());
+
+        // Each old party reshares its share.
+        let reshares = old_indices
+            .iter()
+            .map(|i| {
+                let mut p = Poly::<G::ScalarType>::rand(new_t - 1, &mut thread_rng());
+                p[0] = old_private.eval(*i).value;
+                p
+            })
+            .collect::<Vec<_>>();
+        let reshare_publics = reshares.iter().map(|p| p.commit::<G>()).collect::<Vec<_>>();
+        assert!(Poly::verify_resharing(&old_public, &reshare_publics, &old_indices).is_ok());
+
+        // The new shares are the Lagrange-weighted sums of the reshared shares.
+        let new_shares = (1..=new_n)
+            .map(|i| {
+                let index = ShareIndex::new(i).unwrap();
+                let reshared = old_indices
+                    .iter()
+                    .zip(&reshares)
+                    .map(|(j, p)| Eval {
+                        index: *j,
+                        value: p.eval(index).value,
+                    })
+                    .collect::<Vec<_>>();
+                Eval {
+                    index,
+                    value: Poly::<G::ScalarType>::recover_c0(3, reshared.iter()).unwrap(),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Poly::<G::ScalarType>::recover_c0(new_t, new_shares.iter().skip(2)).unwrap(),
+            *old_private.c0()
+        );
+
+        // Invalid resharings.
+        let mut invalid = reshare_publics.clone();
+        invalid[1].add(&Poly::from(vec![G::generator()]));
+        assert_eq!(
+            Poly::verify_resharing(&old_public, &invalid, &old_indices),
+            Err(FastCryptoError::InvalidProof)
+        );
+        // Errors that cancel out in the Lagrange-weighted sum are also rejected.
+        let lagrange = |j: usize| {
+            let unit = old_indices
+                .iter()
+                .enumerate()
+                .map(|(k, index)| Eval {
+                    index: *index,
+                    value: if k == j {
+                        G::ScalarType::generator()
+                    } else {
+                        G::ScalarType::zero()
+                    },
+                })
+                .collect::<Vec<_>>();
+            Poly::<G::ScalarType>::recover_c0(3, unit.iter()).unwrap()
+        };
+        let mut cancelling = reshare_publics.clone();
+        cancelling[0][0] += G::generator() * lagrange(1);
+        cancelling[1][0] -= G::generator() * lagrange(0);
+        let sum = (0..3).fold(G::zero(), |acc, j| acc + *cancelling[j].c0() * lagrange(j));
+        assert_eq!(sum, *old_public.c0());
+        assert_eq!(
+            Poly::verify_resharing(&old_public, &cancelling, &old_indices),
+            Err(FastCryptoError::InvalidProof)
+        );
+        // Copies of a single reshare are not a resharing.
+        let copies = vec![reshare_publics[0].clone(); 3];
+        assert_eq!(
+            Poly::verify_resharing(&old_public, &copies, &[old_indices[0]; 3]),
+            Err(FastCryptoError::InvalidInput)
+        );
+        let swapped = [old_indices[1], old_indices[0], old_indices[2]];
+        assert_eq!(
+            Poly::verify_resharing(&old_public, &reshare_publics, &swapped),
+            Err(FastCryptoError::InvalidProof)
+        );
+        assert_eq!(
+            Poly::verify_resharing(&old_public, &reshare_publics[..2], &old_indices[..2]),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            Poly::verify_resharing(&old_public, &reshare_publics, &old_indices[..2]),
+            Err(FastCryptoError::InvalidInput)
         );
     }
 
+    #[test]
+    fn test_verify_max_degree<G: GroupElement>() {
+        let mut p = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        assert!(public_p.verify_max_degree(3).is_ok());
+        assert!(public_p.verify_max_degree(10).is_ok());
+        assert!(public_p.verify_max_degree(2).is_err());
+
+        // zero padding is compliant
+        p.add(&Poly::from(vec![G::ScalarType::zero(); 6]));
+        let public_p = p.commit::<G>();
+        assert_eq!(public_p.degree(), 5);
+        assert!(public_p.verify_max_degree(3).is_ok());
+        assert!(public_p.verify_max_degree(2).is_err());
+    }
+
+    #[test]
+    fn test_public_key_shares<G: GroupElement>() {
+        let n = 10;
+        let public_p = Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>();
+        let pk_shares = PublicKeyShares::new(&public_p, n);
+        for i in (1..=n).map(|i| ShareIndex::new(i).unwrap()) {
+            assert_eq!(pk_shares.get(i), Some(&public_p.eval(i).value));
+        }
+        assert_eq!(pk_shares.get(ShareIndex::new(n + 1).unwrap()), None);
+    }
+
+    #[test]
+    fn test_audit_commitment<G: GroupElement>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let mut commitment = p.commit::<G>();
+        assert!(p.audit_commitment(&commitment, &mut thread_rng()).is_ok());
+        commitment.add(&Poly::from(vec![G::zero(), G::generator()]));
+        assert!(p.audit_commitment(&commitment, &mut thread_rng()).is_err());
+    }
+
     #[test]
     fn test_recover_c0_msm_errors<G: GroupElement + MultiScalarMul>() {
         let degree = 4;
@@ -132,6 +2102,57 @@ mod points_tests {
         Poly::<G>::recover_c0_msm(threshold, shares).unwrap_err();
     }
 
+    #[test]
+    fn test_eval_committee_msm<G: GroupElement + MultiScalarMul>() {
+        for degree in [0, 1, 5] {
+            let p = Poly::<G::ScalarType>::rand(degree, &mut thread_rng()).commit::<G>();
+            let evals = p.eval_committee_msm(10).unwrap();
+            assert_eq!(
+                evals,
+                (1..=10)
+                    .map(|i| p.eval(ShareIndex::new(i).unwrap()))
+                    .collect_vec()
+            );
+        }
+        // fewer indices than coefficients
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>();
+        assert_eq!(
+            p.eval_committee_msm(2).unwrap(),
+            (1..=2)
+                .map(|i| p.eval(ShareIndex::new(i).unwrap()))
+                .collect_vec()
+        );
+        assert!(p.eval_committee_msm(0).unwrap().is_empty());
+        assert_eq!(
+            Poly::<G>::from(vec![]).eval_committee_msm(5),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_checked_eval_msm<G: GroupElement + MultiScalarMul>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng()).commit::<G>();
+        assert_eq!(p.checked_eval_msm(I10).unwrap(), p.eval(I10));
+
+        // Size mismatches result in errors instead of panics.
+        let x = G::ScalarType::from(I10.get().into());
+        let powers = std::iter::successors(Some(G::ScalarType::generator()), |p| Some(*p * x))
+            .take(7)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            p.eval_msm_with_powers(&powers[..6]).unwrap(),
+            p.eval(I10).value
+        );
+        assert_eq!(
+            p.eval_msm_with_powers(&powers),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            p.eval_msm_with_powers(&powers[..5]),
+            Err(FastCryptoError::InvalidInput)
+        );
+    }
+
     #[test]
     fn test_recover_c0_msm<G: GroupElement + MultiScalarMul>() {
         let one = G::generator();
@@ -159,6 +2180,143 @@ mod points_tests {
         }
     }
 
+    #[test]
+    fn test_versioned_poly<G: GroupElement + Serialize + DeserializeOwned>() {
+        let p = Poly::<G::ScalarType>::rand(3, &mut thread_rng()).commit::<G>();
+        let versioned = VersionedPoly::from(p.clone());
+        let bytes = versioned.to_bytes();
+        // The version tag precedes the encoding of the polynomial.
+        assert_eq!(bytes[0], 0);
+        assert_eq!(bytes[1..], bcs::to_bytes(&p).unwrap());
+        let decoded = VersionedPoly::<G>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, versioned);
+        assert_eq!(decoded.into_poly(), p);
+
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = 1;
+        assert_eq!(
+            VersionedPoly::<G>::from_bytes(&unknown_version),
+            Err(FastCryptoError::InvalidInput)
+        );
+        assert!(VersionedPoly::<G>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_c0_bytes<G: GroupElement + Serialize + DeserializeOwned>() {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let bytes = public_p.c0_bytes().unwrap();
+        assert_eq!(&Poly::<G>::c0_from_bytes(&bytes).unwrap(), public_p.c0());
+        // The full serialization is the length of the vector followed by the coefficients.
+        let full = bcs::to_bytes(&public_p).unwrap();
+        assert_eq!(&full[1..=bytes.len()], bytes.as_slice());
+        // Invalid inputs should fail.
+        assert!(Poly::<G>::c0_from_bytes(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_prove_equal_c0<G: GroupElement + Serialize>()
+    where
+        G::ScalarType: FiatShamirChallenge,
+    {
+        let ro = RandomOracle::new("test");
+        let a = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let mut b = Poly::<G::ScalarType>::rand(3, &mut thread_rng());
+        let c = b.clone();
+        b.add(&Poly::from(vec![*a.c0() - *b.c0()]));
+        let (public_a, public_b, public_c) = (a.commit::<G>(), b.commit::<G>(), c.commit::<G>());
+
+        // equal secrets
+        let proof = Poly::<G>::prove_equal_c0(&a, &b, &ro, &mut thread_rng()).unwrap();
+        assert!(Poly::verify_equal_c0(&public_a, &public_b, &proof, &ro).is_ok());
+        assert!(Poly::verify_equal_c0(&public_b, &public_a, &proof, &ro).is_err());
+        assert!(
+            Poly::verify_equal_c0(&public_a, &public_b, &proof, &RandomOracle::new("test2"))
+                .is_err()
+        );
+
+        // unequal secrets
+        assert!(Poly::<G>::prove_equal_c0(&a, &c, &ro, &mut thread_rng()).is_err());
+        assert!(Poly::verify_equal_c0(&public_a, &public_c, &proof, &ro).is_err());
+    }
+
+    #[test]
+    fn test_verify_shares_fiat_shamir<G: GroupElement + MultiScalarMul + Serialize>()
+    where
+        G::ScalarType: FiatShamirChallenge,
+    {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let mut shares = (1..=10)
+            .map(|i| {
+                let e = p.eval(ShareIndex::new(i).unwrap());
+                (e.index, e.value)
+            })
+            .collect::<Vec<_>>();
+        assert!(public_p.verify_shares_fiat_shamir(&[], b"test").is_ok());
+        assert!(public_p.verify_shares_fiat_shamir(&shares, b"test").is_ok());
+        assert!(public_p
+            .verify_shares_fiat_shamir(&shares, b"other")
+            .is_ok());
+        // A corrupted share should always fail.
+        shares[3].1 += G::ScalarType::generator();
+        for _ in 0..3 {
+            assert!(public_p
+                .verify_shares_fiat_shamir(&shares, b"test")
+                .is_err());
+        }
+        // Constant polynomials are rejected.
+        let public_p = Poly::<G::ScalarType>::rand(0, &mut thread_rng()).commit::<G>();
+        assert!(public_p
+            .verify_shares_fiat_shamir(&shares, b"test")
+            .is_err());
+    }
+
+    #[test]
+    fn test_fiat_shamir_tags<G: GroupElement + MultiScalarMul + Serialize>()
+    where
+        G::ScalarType: FiatShamirChallenge,
+    {
+        let p = Poly::<G::ScalarType>::rand(5, &mut thread_rng());
+        let public_p = p.commit::<G>();
+        let shares = (1..=10)
+            .map(|i| {
+                let e = p.eval(ShareIndex::new(i).unwrap());
+                (e.index, e.value)
+            })
+            .collect::<Vec<_>>();
+        let (tag_a, tag_b) = (
+            DomainSeparationTag::new(b"protocol a"),
+            DomainSeparationTag::new(b"protocol b"),
+        );
+
+        assert_ne!(
+            public_p.fiat_shamir_challenge(&shares, b"test", &tag_a),
+            public_p.fiat_shamir_challenge(&shares, b"test", &tag_b)
+        );
+        assert_eq!(
+            public_p.fiat_shamir_challenge(&shares, b"test", &tag_a),
+            public_p.fiat_shamir_challenge(&shares, b"test", &tag_a.clone())
+        );
+        for tag in [&tag_a, &tag_b] {
+            assert!(public_p
+                .verify_shares_fiat_shamir_with_tag(&shares, b"test", tag)
+                .is_ok());
+        }
+
+        assert_ne!(
+            public_p.transcript_hash_with_tag(b"session", &tag_a),
+            public_p.transcript_hash_with_tag(b"session", &tag_b)
+        );
+        assert_eq!(
+            public_p.transcript_hash(b"session"),
+            public_p.transcript_hash_with_tag(
+                b"session",
+                &DomainSeparationTag::new(TRANSCRIPT_HASH_DST)
+            )
+        );
+    }
+
     #[test]
     fn test_fast_mult<G: GroupElement>() {
         let x = 1u128 << 109; // 110 bit set
@@ -201,3 +2359,185 @@ mod points_tests {
     #[instantiate_tests(<G2Element>)]
     mod g2_element {}
 }
+
+#[test]
+fn test_pair_coefficients() {
+    let p = Poly::<BlsScalar>::rand(5, &mut thread_rng()).commit::<G1Element>();
+    let h = G2Element::generator() * BlsScalar::rand(&mut thread_rng());
+    let paired = p.pair_coefficients(&h);
+    assert_eq!(paired.degree(), p.degree());
+    for i in [1, 2, 10] {
+        let i = ShareIndex::new(i).unwrap();
+        assert_eq!(paired.eval(i).value, p.eval(i).value.pairing(&h));
+    }
+}
+
+#[test]
+fn test_validate_coefficients() {
+    let p = Poly::<BlsScalar>::rand(5, &mut thread_rng()).commit::<G1Element>();
+    assert!(p.validate_coefficients().is_ok());
+
+    // A point on the curve which is not in G1.
+    let mut bytes = G1Element::generator().to_byte_array();
+    bytes[bytes.len() - 1] += 2;
+    let invalid = G1Element::from_trusted_byte_array(&bytes).unwrap();
+    let mut coefficients = p.as_vec().clone();
+    coefficients[3] = invalid;
+    assert!(Poly::from(coefficients).validate_coefficients().is_err());
+}
+
+#[test]
+fn test_verify_opening() {
+    let degree = 5;
+    let p = Poly::<BlsScalar>::rand(degree, &mut thread_rng());
+    let public_p = p.commit::<G1Element>();
+
+    // Trusted setup with trapdoor s.
+    let s = BlsScalar::rand(&mut thread_rng());
+    let srs = std::iter::successors(Some(G2Element::generator()), |h| Some(*h * s))
+        .take(degree as usize + 1)
+        .collect::<Vec<_>>();
+
+    // The proof for index i is g^{q(s)} for q(x) = (p(x) - p(i)) / (x - i).
+    let i = I10;
+    let i_scalar = BlsScalar::from(i.get() as u128);
+    let value = G1Element::generator() * p.eval(i).value;
+    let q_s = ((p.eval_at_scalar(&s) - p.eval(i).value) / (s - i_scalar)).unwrap();
+    let proof = G1Element::generator() * q_s;
+    assert!(public_p.verify_opening(i, &value, &proof, &srs).is_ok());
+
+    // Forged openings.
+    let other_value = value + G1Element::generator();
+    assert!(public_p
+        .verify_opening(i, &other_value, &proof, &srs)
+        .is_err());
+    assert!(public_p
+        .verify_opening(i, &value, &(proof + G1Element::generator()), &srs)
+        .is_err());
+    assert!(public_p
+        .verify_opening(ShareIndex::new(3).unwrap(), &value, &proof, &srs)
+        .is_err());
+
+    // The srs must cover the degree of the polynomial.
+    assert_eq!(
+        public_p.verify_opening(i, &value, &proof, &srs[..degree as usize]),
+        Err(FastCryptoError::InvalidInput)
+    );
+}
+
+#[test]
+fn test_bytes_compressed() {
+    let p = Poly::<BlsScalar>::rand(5, &mut thread_rng());
+    let public_g1 = p.commit::<G1Element>();
+    let bytes = public_g1.to_bytes_compressed();
+    assert_eq!(bytes.len(), 6 * G1_ELEMENT_BYTE_LENGTH);
+    assert_eq!(Poly::from_bytes_compressed(&bytes).unwrap(), public_g1);
+    // Only the length prefix is omitted compared to the bcs encoding.
+    assert_eq!(bcs::to_bytes(&public_g1).unwrap()[1..], bytes);
+
+    let public_g2 = p.commit::<G2Element>();
+    let bytes = public_g2.to_bytes_compressed();
+    assert_eq!(bytes.len(), 6 * G2_ELEMENT_BYTE_LENGTH);
+    assert_eq!(Poly::from_bytes_compressed(&bytes).unwrap(), public_g2);
+
+    // Malformed point.
+    let mut bytes = public_g1.to_bytes_compressed();
+    bytes[G1_ELEMENT_BYTE_LENGTH..2 * G1_ELEMENT_BYTE_LENGTH].fill(0xff);
+    assert_eq!(
+        Poly::<G1Element>::from_bytes_compressed(&bytes),
+        Err(FastCryptoError::InvalidInput)
+    );
+
+    // Invalid lengths.
+    let bytes = public_g1.to_bytes_compressed();
+    assert!(Poly::<G1Element>::from_bytes_compressed(&bytes[1..]).is_err());
+    assert!(Poly::<G1Element>::from_bytes_compressed(&[]).is_err());
+}
+
+#[test]
+fn test_verify_decryption_shares() {
+    let p = Poly::<BlsScalar>::rand(3, &mut thread_rng());
+    let public_p = p.commit::<G2Element>();
+    let u = G1Element::generator() * BlsScalar::rand(&mut thread_rng());
+    let indices = (1..=5)
+        .map(|i| ShareIndex::new(i).unwrap())
+        .collect::<Vec<_>>();
+    let vks = indices
+        .iter()
+        .map(|i| public_p.eval(*i))
+        .collect::<Vec<_>>();
+    let mut shares = indices
+        .iter()
+        .map(|i| Eval {
+            index: *i,
+            value: u * p.eval(*i).value,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        Poly::<G2Element>::verify_decryption_shares(&vks, &shares, &u).unwrap(),
+        vec![true; 5]
+    );
+
+    // Corrupted shares.
+    shares[1].value += G1Element::generator();
+    shares[3].value = u * p.eval(I10).value;
+    assert_eq!(
+        Poly::<G2Element>::verify_decryption_shares(&vks, &shares, &u).unwrap(),
+        vec![true, false, true, false, true]
+    );
+
+    // Mismatched indices or lengths.
+    assert_eq!(
+        Poly::<G2Element>::verify_decryption_shares(&vks[..4], &shares, &u),
+        Err(FastCryptoError::InvalidInput)
+    );
+    shares.swap(0, 1);
+    assert_eq!(
+        Poly::<G2Element>::verify_decryption_shares(&vks, &shares, &u),
+        Err(FastCryptoError::InvalidInput)
+    );
+}
+
+#[test]
+fn test_eval_from_bytes_canonical_unreduced() {
+    // The order of the BLS12-381 scalar field, in big-endian.
+    const MODULUS: [u8; 32] = [
+        0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8,
+        0x05, 0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00,
+        0x00, 0x01,
+    ];
+    let share = Eval {
+        index: I10,
+        value: BlsScalar::from(7),
+    };
+    let bytes = bcs::to_bytes(&share).unwrap();
+    assert_eq!(Eval::from_bytes_canonical(&bytes).unwrap(), share);
+
+    // The same value plus the modulus encodes the same share if reduced, and must be rejected.
+    let mut unreduced = bytes.clone();
+    let mut carry = 0u16;
+    for (b, m) in unreduced[4..].iter_mut().zip(MODULUS).rev() {
+        let sum = *b as u16 + m as u16 + carry;
+        *b = sum as u8;
+        carry = sum >> 8;
+    }
+    assert_eq!(
+        Eval::<BlsScalar>::from_bytes_canonical(&unreduced),
+        Err(FastCryptoError::InvalidInput)
+    );
+}
+
+#[test]
+fn test_shares_needed_for_robustness() {
+    assert_eq!(shares_needed_for_robustness(0, 5, 0), 5);
+    assert_eq!(shares_needed_for_robustness(3, 5, 0), 2);
+    assert_eq!(shares_needed_for_robustness(5, 5, 0), 0);
+    assert_eq!(shares_needed_for_robustness(5, 5, 2), 4);
+    assert_eq!(shares_needed_for_robustness(8, 5, 2), 1);
+    assert_eq!(shares_needed_for_robustness(9, 5, 2), 0);
+    assert_eq!(shares_needed_for_robustness(20, 5, 2), 0);
+    assert_eq!(
+        shares_needed_for_robustness(0, u32::MAX, u32::MAX),
+        u32::MAX
+    );
+}
@@ -0,0 +1,107 @@
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::polynomial::Poly;
+use crate::test_utils::{
+    all_authorized_subsets, generate_dkg_fixture, verify_all_subsets_reconstruct,
+    MAX_AUTHORIZED_SUBSETS,
+};
+use crate::types::ShareIndex;
+use fastcrypto::error::FastCryptoError;
+use fastcrypto::groups::bls12381::{G2Element, Scalar as BlsScalar};
+use fastcrypto::groups::ristretto255::{RistrettoPoint, RistrettoScalar};
+use fastcrypto::groups::GroupElement;
+use rand::thread_rng;
+
+fn check_dkg_fixture<G: GroupElement>() {
+    let (t, n) = (3, 7);
+    let fixture = generate_dkg_fixture::<G, _>(t, n, &mut thread_rng());
+    assert_eq!(fixture.private_polys.len(), n as usize);
+    assert_eq!(fixture.shares.len(), n as usize);
+
+    for (public_poly, shares) in fixture.public_polys.iter().zip(&fixture.shares) {
+        assert_eq!(shares.len(), n as usize);
+        for share in shares {
+            assert!(public_poly.verify_share(share.index, &share.value).is_ok());
+        }
+    }
+    for share in &fixture.aggregate_shares {
+        assert!(Poly::verify_aggregate_share(
+            &fixture.aggregate_public_poly,
+            share.index,
+            &share.value
+        )
+        .is_ok());
+    }
+
+    let secret = fixture
+        .private_polys
+        .iter()
+        .fold(G::ScalarType::zero(), |acc, p| acc + *p.c0());
+    assert_eq!(
+        Poly::<G::ScalarType>::recover_c0(
+            t,
+            fixture.aggregate_shares.iter().skip(2).take(t as usize)
+        )
+        .unwrap(),
+        secret
+    );
+    assert_eq!(fixture.aggregate_public_key, G::generator() * secret);
+    assert_eq!(
+        fixture.aggregate_public_poly.c0(),
+        &fixture.aggregate_public_key
+    );
+}
+
+#[test]
+fn test_dkg_fixture() {
+    check_dkg_fixture::<G2Element>();
+    check_dkg_fixture::<RistrettoPoint>();
+}
+
+#[test]
+fn test_all_authorized_subsets() {
+    let subsets = all_authorized_subsets(3, 5).collect::<Vec<_>>();
+    assert_eq!(subsets.len(), 10);
+    assert!(subsets
+        .iter()
+        .all(|s| s.len() == 3 && s.windows(2).all(|w| w[0] < w[1])));
+    assert_eq!(
+        subsets[0],
+        (1..=3)
+            .map(|i| ShareIndex::new(i).unwrap())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        subsets
+            .iter()
+            .collect::<std::collections::HashSet<_>>()
+            .len(),
+        10
+    );
+    assert_eq!(all_authorized_subsets(5, 5).count(), 1);
+
+    // There are (30 choose 10) subsets, so they are sampled.
+    let sampled = all_authorized_subsets(10, 30).collect::<Vec<_>>();
+    assert_eq!(sampled.len(), MAX_AUTHORIZED_SUBSETS);
+    assert!(sampled
+        .iter()
+        .all(|s| s.len() == 10 && s.windows(2).all(|w| w[0] < w[1]) && s[9].get() <= 30));
+}
+
+#[test]
+fn test_verify_all_subsets_reconstruct() {
+    let (t, n) = (3, 6);
+    let p = Poly::<BlsScalar>::rand(t - 1, &mut thread_rng());
+    assert!(verify_all_subsets_reconstruct(&p, t, n).is_ok());
+    assert!(verify_all_subsets_reconstruct(&p.commit::<G2Element>(), t, n).is_ok());
+    let p = Poly::<RistrettoScalar>::rand(t - 1, &mut thread_rng());
+    assert!(verify_all_subsets_reconstruct(&p.commit::<RistrettoPoint>(), t, n).is_ok());
+
+    // With a higher degree, reconstructions from different subsets disagree.
+    let p = Poly::<BlsScalar>::rand(t, &mut thread_rng());
+    assert_eq!(
+        verify_all_subsets_reconstruct(&p, t, n),
+        Err(FastCryptoError::InvalidProof)
+    );
+}
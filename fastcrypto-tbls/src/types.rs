@@ -5,8 +5,10 @@ use crate::polynomial::{Eval, PublicPoly};
 use crate::{ecies, tbls};
 use fastcrypto::error::{FastCryptoError, FastCryptoResult};
 use fastcrypto::groups::ristretto255::RistrettoPoint;
-use fastcrypto::groups::{bls12381, GroupElement, HashToGroupElement, Pairing};
+use fastcrypto::groups::{bls12381, GroupElement, HashToGroupElement, Pairing, Scalar};
+use fastcrypto::serde_helpers::ToFromByteArray;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::num::NonZeroU32;
 
 /// Implementation of [ThresholdBls] for BLS12-381-min-sig. A variant for BLS12-381-min-pk can be
@@ -50,6 +52,167 @@ pub struct IndexedValue<A> {
     pub value: A,
 }
 
+impl<A> IndexedValue<A> {
+    /// Compares two values by their indices only, e.g., to sort shares with
+    /// `shares.sort_by(IndexedValue::cmp_by_index)` and then binary search them with
+    /// `shares.binary_search_by_key(&index, |s| s.index)`. This is not an implementation of [Ord]
+    /// since two values with the same index but different values would then compare as equal
+    /// while not being equal.
+    pub fn cmp_by_index(&self, other: &Self) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl<A: GroupElement> IndexedValue<A> {
+    /// Returns the difference of two values with the same index, e.g., of two shares for the same
+    /// party but from different sharings. Returns an error if the indices differ.
+    pub fn sub(&self, other: &Self) -> FastCryptoResult<Self> {
+        if self.index != other.index {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Ok(IndexedValue {
+            index: self.index,
+            value: self.value - other.value,
+        })
+    }
+}
+
+impl<S: Scalar> IndexedValue<S> {
+    /// Returns base * share with the same index, e.g., a partial signature H(m)^{s_i} or a partial
+    /// decryption when the value is the share s_i of party i.
+    pub fn exponentiate_base<G: GroupElement<ScalarType = S>>(&self, base: &G) -> IndexedValue<G> {
+        IndexedValue {
+            index: self.index,
+            value: *base * self.value,
+        }
+    }
+
+    /// Returns the scalar that masks this share to the target value, i.e., `target - share` such
+    /// that `share + blinding == target`, e.g., for re-randomizing shares in an MPC protocol. The
+    /// target is a plain scalar since it is only meaningful for the index of this share. Never
+    /// fails, the result is returned as a [FastCryptoResult] so it can be chained with `?` like
+    /// [IndexedValue::sub].
+    pub fn blinding_to(&self, target: &S) -> FastCryptoResult<S> {
+        Ok(*target - self.value)
+    }
+
+    /// Deserializes a share and rejects any encoding other than the canonical one, e.g., a scalar
+    /// that is not reduced modulo the group order, since accepting it would let a party present
+    /// two different encodings of the same share. Returns [FastCryptoError::InvalidInput] if the
+    /// bytes are malformed or not canonical.
+    pub fn from_bytes_canonical(bytes: &[u8]) -> FastCryptoResult<Self> {
+        let share: Self = bcs::from_bytes(bytes).map_err(|_| FastCryptoError::InvalidInput)?;
+        if bcs::to_bytes(&share).expect("serialize should never fail") != bytes {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        Ok(share)
+    }
+}
+
+/// Explicit byte orders for cross-language test vectors: An index is encoded as 4 bytes and a scalar
+/// as N bytes, each in the given byte order, regardless of the canonical encoding of the scalar
+/// (e.g., big-endian for BLS12-381 and little-endian for Ristretto255). Big-endian is the
+/// convention used for test vectors. Note that [bcs] encodes indices as little-endian and scalars
+/// using their canonical encoding.
+impl<S: Scalar> IndexedValue<S> {
+    pub fn to_bytes_be<const N: usize>(&self) -> Vec<u8>
+    where
+        S: ToFromByteArray<N>,
+    {
+        self.to_bytes_with_order(true)
+    }
+
+    pub fn to_bytes_le<const N: usize>(&self) -> Vec<u8>
+    where
+        S: ToFromByteArray<N>,
+    {
+        self.to_bytes_with_order(false)
+    }
+
+    /// Inverse of [IndexedValue::to_bytes_be]. Returns [FastCryptoError::InvalidInput] if the
+    /// length is not 4 + N, the index is zero or the scalar is not canonical.
+    pub fn from_bytes_be<const N: usize>(bytes: &[u8]) -> FastCryptoResult<Self>
+    where
+        S: ToFromByteArray<N>,
+    {
+        Self::from_bytes_with_order(bytes, true)
+    }
+
+    /// Inverse of [IndexedValue::to_bytes_le], see [IndexedValue::from_bytes_be].
+    pub fn from_bytes_le<const N: usize>(bytes: &[u8]) -> FastCryptoResult<Self>
+    where
+        S: ToFromByteArray<N>,
+    {
+        Self::from_bytes_with_order(bytes, false)
+    }
+
+    fn to_bytes_with_order<const N: usize>(&self, big_endian: bool) -> Vec<u8>
+    where
+        S: ToFromByteArray<N>,
+    {
+        let index = match big_endian {
+            true => self.index.get().to_be_bytes(),
+            false => self.index.get().to_le_bytes(),
+        };
+        index
+            .into_iter()
+            .chain(scalar_to_bytes(&self.value, big_endian))
+            .collect()
+    }
+
+    fn from_bytes_with_order<const N: usize>(
+        bytes: &[u8],
+        big_endian: bool,
+    ) -> FastCryptoResult<Self>
+    where
+        S: ToFromByteArray<N>,
+    {
+        if bytes.len() != 4 + N {
+            return Err(FastCryptoError::InvalidInput);
+        }
+        let index: [u8; 4] = bytes[..4].try_into().expect("length is 4");
+        let index = match big_endian {
+            true => u32::from_be_bytes(index),
+            false => u32::from_le_bytes(index),
+        };
+        Ok(IndexedValue {
+            index: ShareIndex::new(index).ok_or(FastCryptoError::InvalidInput)?,
+            value: scalar_from_bytes(bytes[4..].try_into().expect("length is N"), big_endian)?,
+        })
+    }
+}
+
+/// Returns true if the canonical encoding of the scalar type is little-endian, i.e., if the
+/// encoding of one starts with the non-zero byte.
+fn is_little_endian<S: Scalar + ToFromByteArray<N>, const N: usize>() -> bool {
+    S::generator().to_byte_array()[0] == 1
+}
+
+/// Encodes a scalar as an N-byte integer in the given byte order.
+pub(crate) fn scalar_to_bytes<S: Scalar + ToFromByteArray<N>, const N: usize>(
+    scalar: &S,
+    big_endian: bool,
+) -> [u8; N] {
+    let mut bytes = scalar.to_byte_array();
+    if big_endian == is_little_endian::<S, N>() {
+        bytes.reverse();
+    }
+    bytes
+}
+
+/// Inverse of [scalar_to_bytes]. Returns [FastCryptoError::InvalidInput] if the integer is not
+/// reduced.
+pub(crate) fn scalar_from_bytes<S: Scalar + ToFromByteArray<N>, const N: usize>(
+    bytes: &[u8; N],
+    big_endian: bool,
+) -> FastCryptoResult<S> {
+    let mut bytes = *bytes;
+    if big_endian == is_little_endian::<S, N>() {
+        bytes.reverse();
+    }
+    S::from_byte_array(&bytes)
+}
+
 /// Basic wrapper of a set of values that are not associated with indexes, assuming the indexes are known to all
 /// parties. Used to reduce the size of the messages in the protocol.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -85,6 +248,24 @@ impl<A> UnindexedValues<A> {
     }
 }
 
+/// Extension for adding context to errors that are propagated using `?`, e.g.,
+/// `Poly::recover_c0(t, shares).with_context("reconstruction")?` returns a
+/// [FastCryptoError::WithContext] error with the message "reconstruction failed: Invalid value was
+/// given to the function" if the recovery fails. The original error is kept as the source and can
+/// be retrieved with [FastCryptoError::without_context].
+pub trait WithContext<T> {
+    fn with_context(self, context: &str) -> FastCryptoResult<T>;
+}
+
+impl<T> WithContext<T> for FastCryptoResult<T> {
+    fn with_context(self, context: &str) -> FastCryptoResult<T> {
+        self.map_err(|e| FastCryptoError::WithContext {
+            context: context.to_string(),
+            source: Box::new(e),
+        })
+    }
+}
+
 /// ECIES related types with Ristretto points.
 ///
 pub type PrivateEciesKey = ecies::PrivateKey<RistrettoPoint>;
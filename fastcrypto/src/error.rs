@@ -55,6 +55,25 @@ pub enum FastCryptoError {
     /// General opaque cryptographic error.
     #[error("General cryptographic error")]
     GeneralOpaqueError,
+
+    /// An error that was propagated with additional context, e.g., the step of a protocol that
+    /// failed. The original error is kept as the source.
+    #[error("{context} failed: {source}")]
+    WithContext {
+        context: String,
+        source: Box<FastCryptoError>,
+    },
+}
+
+impl FastCryptoError {
+    /// Returns the original error without any context added by [FastCryptoError::WithContext],
+    /// e.g., to match on the variant of an error that was propagated through several layers.
+    pub fn without_context(&self) -> &FastCryptoError {
+        match self {
+            FastCryptoError::WithContext { source, .. } => source.without_context(),
+            e => e,
+        }
+    }
 }
 
 impl From<signature::Error> for FastCryptoError {
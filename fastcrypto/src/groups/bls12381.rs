@@ -33,6 +33,7 @@ use serde::{de, Deserialize};
 use std::fmt::Debug;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::ptr;
+use zeroize::Zeroize;
 
 /// Elements of the group G_1 in BLS 12-381.
 #[derive(Clone, Copy, Eq, PartialEq, GroupOpsExtend)]
@@ -715,6 +716,12 @@ impl ScalarType for Scalar {
     }
 }
 
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.l.zeroize();
+    }
+}
+
 /// Reduce a big-endian integer of arbitrary size modulo the scalar field size and return the scalar.
 /// If the input bytes are uniformly distributed, the output will be uniformly distributed in the
 /// scalar field.
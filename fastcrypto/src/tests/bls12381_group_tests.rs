@@ -18,6 +18,7 @@ use blst::{
     blst_p2_affine_serialize, blst_p2_deserialize, BLST_ERROR,
 };
 use rand::{rngs::StdRng, thread_rng, SeedableRng as _};
+use zeroize::Zeroize;
 
 const MSG: &[u8] = b"test message";
 
@@ -55,6 +56,13 @@ fn test_scalar_arithmetic() {
     assert_eq!(res.unwrap(), Scalar::from(2 << 63));
 }
 
+#[test]
+fn test_scalar_zeroize() {
+    let mut s = Scalar::rand(&mut thread_rng());
+    s.zeroize();
+    assert_eq!(s, Scalar::zero());
+}
+
 #[test]
 fn test_g1_arithmetic() {
     // Test that different ways of computing [5]G gives the expected result